@@ -94,7 +94,7 @@ def test_cellular_texture():
     for ptype in pattern_types:
         print(f"\n{ptype.upper()}: cell_size=5.0mm")
         start = time.time()
-        paths, points = noise.generate_cellular_texture(
+        paths, points, radii = noise.generate_cellular_texture(
             cell_size=5.0,
             threshold=0.0,
             pattern_type=ptype
@@ -169,7 +169,7 @@ def test_comprehensive():
 
     # Cellular
     start = time.time()
-    paths, circles = noise.generate_cellular_texture(cell_size=5.0, pattern_type="squares")
+    paths, circles, radii = noise.generate_cellular_texture(cell_size=5.0, pattern_type="squares")
     t3 = time.time() - start
 
     total_time = time.time() - total_start
@@ -0,0 +1,19 @@
+//! Cooperative cancellation
+//!
+//! Long-running generators take an optional `should_cancel` callable. They
+//! release the GIL while they work and briefly take it back every so often
+//! to poll the callable; once it returns a truthy value they stop early and
+//! return whatever they have built so far.
+
+use pyo3::prelude::*;
+
+/// Whether the `should_cancel` callback asks to stop
+///
+/// Acquires the GIL for the call, so it can be polled from inside
+/// `allow_threads`. An exception raised by the callback is propagated.
+pub(crate) fn requested(should_cancel: Option<&PyObject>) -> PyResult<bool> {
+    match should_cancel {
+        Some(callback) => Python::with_gil(|py| callback.call0(py)?.is_truthy(py)),
+        None => Ok(false),
+    }
+}
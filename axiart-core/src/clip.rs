@@ -0,0 +1,106 @@
+//! Clipping for path sets
+//!
+//! Trims generator output to a region of the page, or removes the parts hidden
+//! behind foreground shapes, splitting paths where they cross a boundary.
+
+use crate::geometry::{clip_polyline_to_polygon, clip_polyline_to_rect, point_in_polygon, Rect};
+use crate::paths::Paths;
+use crate::threads;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+/// Clip polylines to a rectangle
+///
+/// Each polyline is clipped segment by segment (Cohen–Sutherland) and split
+/// into separate pieces wherever it leaves the rectangle and re-enters.
+/// Points exactly on the boundary count as inside.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `x0`, `y0`, `x1`, `y1` - Two opposite corners of the rectangle, e.g.
+///   `0, 0, width, height` for the page
+#[pyfunction]
+#[pyo3(signature = (paths, x0=0.0, y0=0.0, x1=297.0, y1=210.0))]
+pub fn clip_to_rect(paths: Paths, x0: f64, y0: f64, x1: f64, y1: f64) -> Paths {
+    let rect = Rect::new(x0, y0, x1, y1);
+    Paths(
+        paths
+            .0
+            .iter()
+            .flat_map(|path| clip_polyline_to_rect(path, &rect))
+            .collect(),
+    )
+}
+
+/// Remove the parts of paths hidden behind closed shapes
+///
+/// Hidden-line removal for layered compositions: every portion of `paths`
+/// that lies inside any occluder polygon is cut away, splitting polylines
+/// where they cross an occluder's boundary. Draw the occluders themselves on
+/// a foreground layer to mask background hatching or textures.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `occluders` - Closed polygons (open or closed vertex lists) in front of
+///   the paths
+#[pyfunction]
+pub fn occlude(paths: Paths, occluders: Vec<Vec<(f64, f64)>>) -> Paths {
+    let bounds: Vec<Rect> = occluders
+        .iter()
+        .map(|polygon| bounding_rect(polygon))
+        .collect();
+
+    Paths(threads::install(|| {
+        paths
+            .0
+            .into_par_iter()
+            .flat_map_iter(|path| {
+                if path.len() == 1 {
+                    let (x, y) = path[0];
+                    let hidden = occluders
+                        .iter()
+                        .any(|polygon| point_in_polygon(x, y, polygon));
+                    return if hidden { Vec::new() } else { vec![path] };
+                }
+
+                let mut pieces = vec![path];
+                for (polygon, rect) in occluders.iter().zip(&bounds) {
+                    pieces = pieces
+                        .into_iter()
+                        .flat_map(|piece| {
+                            // Pieces clear of the occluder's bounds are untouched
+                            if !overlaps(&bounding_rect(&piece), rect) {
+                                vec![piece]
+                            } else {
+                                clip_polyline_to_polygon(&piece, polygon, false)
+                            }
+                        })
+                        .collect();
+                }
+                pieces
+            })
+            .collect()
+    }))
+}
+
+/// Axis-aligned bounds of a point list
+fn bounding_rect(points: &[(f64, f64)]) -> Rect {
+    points.iter().fold(
+        Rect {
+            x0: f64::INFINITY,
+            y0: f64::INFINITY,
+            x1: f64::NEG_INFINITY,
+            y1: f64::NEG_INFINITY,
+        },
+        |r, &(x, y)| Rect {
+            x0: r.x0.min(x),
+            y0: r.y0.min(y),
+            x1: r.x1.max(x),
+            y1: r.y1.max(y),
+        },
+    )
+}
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    a.x0 <= b.x1 && b.x0 <= a.x1 && a.y0 <= b.y1 && b.y0 <= a.y1
+}
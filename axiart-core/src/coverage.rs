@@ -0,0 +1,140 @@
+//! Ink coverage estimation
+//!
+//! Rasterizes strokes at their drawn width onto a coarse grid to predict how
+//! dark a plot will read and how much ink it will take, before committing to
+//! a long plot.
+
+use crate::error::AxiArtError;
+use crate::geometry::point_segment_distance;
+use crate::paths::{Bounds, Paths};
+use numpy::{PyArray1, PyArrayMethods};
+use pyo3::prelude::*;
+
+/// Samples per grid cell along each axis
+const SUBSAMPLES: usize = 4;
+
+/// Estimate the fraction of an area covered by ink
+///
+/// Each grid cell is sampled on a 4×4 sub-grid; a sample is inked when it
+/// lies within half the line width of any stroke. A cell's coverage is its
+/// inked fraction, so strokes thinner than a cell still register.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points; single points are dots
+/// * `line_width` - Pen stroke width, in path units
+/// * `grid_resolution` - Number of grid cells along the longer side
+/// * `bounds` - Area to measure as (min_x, min_y, max_x, max_y), e.g. the
+///   page (defaults to the paths' extent plus half the line width)
+/// * `return_grid` - Also return the per-cell coverage
+///
+/// # Returns
+/// Covered fraction of the area (0 to 1), or (fraction, grid) with
+/// `return_grid`, where grid is a 2D NumPy array of per-cell coverage with
+/// rows running along y
+#[pyfunction]
+#[pyo3(signature = (paths, line_width=0.5, grid_resolution=100, bounds=None, return_grid=false))]
+pub fn ink_coverage(
+    py: Python<'_>,
+    paths: Paths,
+    line_width: f64,
+    grid_resolution: usize,
+    bounds: Option<Bounds>,
+    return_grid: bool,
+) -> PyResult<PyObject> {
+    if line_width <= 0.0 {
+        return Err(AxiArtError::InvalidParameter("line_width must be positive".into()).into());
+    }
+    if grid_resolution == 0 {
+        return Err(
+            AxiArtError::InvalidParameter("grid_resolution must be positive".into()).into(),
+        );
+    }
+
+    let half_width = line_width / 2.0;
+    let area = bounds.or_else(|| {
+        paths.bounds().map(|(x0, y0, x1, y1)| {
+            (
+                x0 - half_width,
+                y0 - half_width,
+                x1 + half_width,
+                y1 + half_width,
+            )
+        })
+    });
+    let (fraction, nx, ny, grid) = match area {
+        Some(area) => {
+            let (x0, y0, x1, y1) = area;
+            if x1 <= x0 || y1 <= y0 {
+                return Err(
+                    AxiArtError::InvalidParameter("bounds must have positive size".into()).into(),
+                );
+            }
+            let (nx, ny, grid) = coverage_grid(&paths, half_width, area, grid_resolution);
+            let fraction = grid.iter().sum::<f64>() / grid.len() as f64;
+            (fraction, nx, ny, grid)
+        }
+        // Nothing drawn and nothing to measure
+        None => (0.0, 0, 0, Vec::new()),
+    };
+
+    if return_grid {
+        let grid = PyArray1::from_vec_bound(py, grid).reshape([ny, nx])?;
+        Ok((fraction, grid).into_py(py))
+    } else {
+        Ok(fraction.into_py(py))
+    }
+}
+
+/// Per-cell inked fraction over `area`, as (columns, rows, row-major cells)
+fn coverage_grid(
+    paths: &Paths,
+    half_width: f64,
+    (x0, y0, x1, y1): Bounds,
+    grid_resolution: usize,
+) -> (usize, usize, Vec<f64>) {
+    let cell = (x1 - x0).max(y1 - y0) / grid_resolution as f64;
+    let nx = ((x1 - x0) / cell).ceil().max(1.0) as usize;
+    let ny = ((y1 - y0) / cell).ceil().max(1.0) as usize;
+
+    // Inked samples on the fine grid, sample (i, j) centered at
+    // (x0 + (i + 0.5) * step, y0 + (j + 0.5) * step)
+    let step = cell / SUBSAMPLES as f64;
+    let (sx, sy) = (nx * SUBSAMPLES, ny * SUBSAMPLES);
+    let mut inked = vec![false; sx * sy];
+    let sample_range = |lo: f64, hi: f64, origin: f64, count: usize| {
+        let first = ((lo - origin) / step - 0.5).ceil().max(0.0) as usize;
+        let last = (((hi - origin) / step - 0.5).floor().max(-1.0) + 1.0) as usize;
+        first..last.min(count)
+    };
+
+    for path in &paths.0 {
+        let segments = path
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .chain(path.first().filter(|_| path.len() == 1).map(|&p| (p, p)));
+        for (a, b) in segments {
+            let xs = sample_range(a.0.min(b.0) - half_width, a.0.max(b.0) + half_width, x0, sx);
+            let ys = sample_range(a.1.min(b.1) - half_width, a.1.max(b.1) + half_width, y0, sy);
+            for j in ys {
+                let y = y0 + (j as f64 + 0.5) * step;
+                for i in xs.clone() {
+                    let x = x0 + (i as f64 + 0.5) * step;
+                    if !inked[j * sx + i] && point_segment_distance((x, y), a, b) <= half_width {
+                        inked[j * sx + i] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    let per_cell = (SUBSAMPLES * SUBSAMPLES) as f64;
+    let mut grid = vec![0.0; nx * ny];
+    for j in 0..sy {
+        for i in 0..sx {
+            if inked[j * sx + i] {
+                grid[(j / SUBSAMPLES) * nx + i / SUBSAMPLES] += 1.0 / per_cell;
+            }
+        }
+    }
+    (nx, ny, grid)
+}
@@ -0,0 +1,165 @@
+//! Delaunay triangulation (Bowyer–Watson)
+//!
+//! Incremental triangulation used by the Voronoi generator for exact cell
+//! boundaries and triangulation output. Points are inserted one at a time into
+//! an enclosing super-triangle; triangles whose circumcircle contains the new
+//! point are removed and the resulting cavity is re-triangulated.
+
+use std::collections::HashMap;
+
+/// Triangle with its cached circumcircle
+struct Triangle {
+    vertices: [usize; 3],
+    center: (f64, f64),
+    radius_sq: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[(f64, f64)]) -> Self {
+        let (center, radius_sq) = circumcircle(
+            points[vertices[0]],
+            points[vertices[1]],
+            points[vertices[2]],
+        );
+        Triangle {
+            vertices,
+            center,
+            radius_sq,
+        }
+    }
+
+    #[inline]
+    fn circumcircle_contains(&self, (x, y): (f64, f64)) -> bool {
+        let dx = x - self.center.0;
+        let dy = y - self.center.1;
+        dx * dx + dy * dy < self.radius_sq
+    }
+}
+
+/// Circumcenter and squared circumradius of a triangle
+///
+/// Degenerate (collinear) triangles get an infinite circle so they are always
+/// replaced by the next insertion.
+fn circumcircle(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> ((f64, f64), f64) {
+    let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+    if d.abs() < 1e-12 {
+        return ((0.0, 0.0), f64::INFINITY);
+    }
+
+    let a2 = a.0 * a.0 + a.1 * a.1;
+    let b2 = b.0 * b.0 + b.1 * b.1;
+    let c2 = c.0 * c.0 + c.1 * c.1;
+    let ux = (a2 * (b.1 - c.1) + b2 * (c.1 - a.1) + c2 * (a.1 - b.1)) / d;
+    let uy = (a2 * (c.0 - b.0) + b2 * (a.0 - c.0) + c2 * (b.0 - a.0)) / d;
+
+    let dx = a.0 - ux;
+    let dy = a.1 - uy;
+    ((ux, uy), dx * dx + dy * dy)
+}
+
+/// Triangulate a point set
+///
+/// Returns triangles as index triples into `points`. Points that coincide with
+/// an already inserted point are skipped and appear in no triangle.
+pub(crate) fn triangulate(points: &[(f64, f64)]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    bowyer_watson(points)
+        .into_iter()
+        .filter(|v| v.iter().all(|&i| i < n))
+        .collect()
+}
+
+/// Delaunay neighbors of every point
+///
+/// Also keeps adjacencies that only appear in triangles touching the
+/// super-triangle, so nearly collinear points on the convex hull keep their
+/// hull neighbors. Lists are sorted and deduplicated.
+pub(crate) fn neighbors(points: &[(f64, f64)]) -> Vec<Vec<usize>> {
+    let n = points.len();
+    let mut adjacency = vec![Vec::new(); n];
+
+    for [a, b, c] in bowyer_watson(points) {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            if u < n && v < n {
+                adjacency[u].push(v);
+                adjacency[v].push(u);
+            }
+        }
+    }
+
+    for list in adjacency.iter_mut() {
+        list.sort_unstable();
+        list.dedup();
+    }
+
+    adjacency
+}
+
+/// Unique undirected edges of a triangulation, as sorted index pairs
+pub(crate) fn triangle_edges(triangles: &[[usize; 3]]) -> Vec<(usize, usize)> {
+    let mut edges: Vec<(usize, usize)> = triangles
+        .iter()
+        .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+        .map(|(u, v)| (u.min(v), u.max(v)))
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+/// Run Bowyer–Watson, returning every triangle including those that use the
+/// super-triangle vertices (indices `n`, `n + 1` and `n + 2`)
+fn bowyer_watson(points: &[(f64, f64)]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    if n < 2 {
+        return Vec::new();
+    }
+
+    // Super-triangle far enough out that it cannot influence triangles whose
+    // circumcircles lie near the input
+    let (min_x, min_y, max_x, max_y) = points.iter().fold(
+        (
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(x0, y0, x1, y1), &(x, y)| (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+    );
+    let span = (max_x - min_x).max(max_y - min_y).max(1.0) * 100.0;
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    let mut all_points = points.to_vec();
+    all_points.push((mid_x - 2.0 * span, mid_y - span));
+    all_points.push((mid_x + 2.0 * span, mid_y - span));
+    all_points.push((mid_x, mid_y + 2.0 * span));
+
+    let mut triangles = vec![Triangle::new([n, n + 1, n + 2], &all_points)];
+
+    for (idx, &p) in points.iter().enumerate() {
+        let (bad, good): (Vec<Triangle>, Vec<Triangle>) = triangles
+            .into_iter()
+            .partition(|t| t.circumcircle_contains(p));
+        triangles = good;
+
+        // Cavity boundary: edges of removed triangles that are not shared
+        let mut edge_count: HashMap<(usize, usize), usize> = HashMap::new();
+        for t in &bad {
+            let [a, b, c] = t.vertices;
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                *edge_count.entry((u.min(v), u.max(v))).or_insert(0) += 1;
+            }
+        }
+
+        for t in &bad {
+            let [a, b, c] = t.vertices;
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                if edge_count[&(u.min(v), u.max(v))] == 1 {
+                    triangles.push(Triangle::new([u, v, idx], &all_points));
+                }
+            }
+        }
+    }
+
+    triangles.into_iter().map(|t| t.vertices).collect()
+}
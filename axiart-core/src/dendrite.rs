@@ -6,75 +6,21 @@
 //! - No bucket size limits or capacity issues
 //! - Industry-standard approach for particle simulations
 
+use crate::cancel;
+use crate::error::AxiArtError;
+use crate::paths::{Bounds, Generator, Paths};
+use crate::seed::resolve_seed;
+use crate::spatial_hash::SpatialHash;
 use pyo3::prelude::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
-use std::collections::HashMap;
 use std::f64::consts::PI;
 
-/// Spatial grid hash for ultra-fast nearest neighbor queries
-///
-/// Divides space into uniform cells based on attraction distance.
-/// Nearest neighbor search only checks 9 cells (3x3 grid) - O(1) typical case.
-/// Handles any amount of clustering with zero capacity limits.
-struct SpatialGrid {
-    cell_size: f64,
-    grid: HashMap<(i32, i32), Vec<usize>>,
-}
-
-impl SpatialGrid {
-    fn new(cell_size: f64) -> Self {
-        SpatialGrid {
-            cell_size,
-            grid: HashMap::new(),
-        }
-    }
-
-    /// Convert world coordinates to grid cell coordinates
-    #[inline]
-    fn get_cell(&self, x: f64, y: f64) -> (i32, i32) {
-        (
-            (x / self.cell_size).floor() as i32,
-            (y / self.cell_size).floor() as i32,
-        )
-    }
-
-    /// Insert a point into the spatial grid
-    fn insert(&mut self, x: f64, y: f64, idx: usize) {
-        let cell = self.get_cell(x, y);
-        self.grid.entry(cell).or_insert_with(Vec::new).push(idx);
-    }
-
-    /// Find nearest neighbor by checking 3x3 grid of cells
-    /// Returns (index, distance_squared) or None
-    fn find_nearest(&self, x: f64, y: f64, points: &[(f64, f64)]) -> Option<(usize, f64)> {
-        let center_cell = self.get_cell(x, y);
-        let mut best: Option<(usize, f64)> = None;
-
-        // Check 3x3 grid of cells around query point
-        for dx in -1..=1 {
-            for dy in -1..=1 {
-                let cell = (center_cell.0 + dx, center_cell.1 + dy);
-                if let Some(indices) = self.grid.get(&cell) {
-                    for &idx in indices {
-                        let (px, py) = points[idx];
-                        let dist_sq = (px - x) * (px - x) + (py - y) * (py - y);
-
-                        match best {
-                            None => best = Some((idx, dist_sq)),
-                            Some((_, best_dist)) if dist_sq < best_dist => {
-                                best = Some((idx, dist_sq));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-        }
+/// Particles added between polls of the `should_cancel` callback
+const CANCEL_POLL_INTERVAL: usize = 100;
 
-        best
-    }
-}
+/// Tree nodes and the branch segments joining them
+type Tree = (Vec<(f64, f64)>, Vec<((f64, f64), (f64, f64))>);
 
 /// Branching style for dendrite growth
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -93,9 +39,10 @@ impl BranchingStyle {
             "radial" => Ok(BranchingStyle::Radial),
             "vertical" => Ok(BranchingStyle::Vertical),
             "horizontal" => Ok(BranchingStyle::Horizontal),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(
-                "Invalid branching style. Use 'radial', 'vertical', or 'horizontal'",
-            )),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid branching style. Use 'radial', 'vertical', or 'horizontal'".into(),
+            )
+            .into()),
         }
     }
 }
@@ -128,6 +75,9 @@ pub struct DendriteGenerator {
     min_move_distance: f64,
     branching_style: BranchingStyle,
     seed_points: Vec<(f64, f64)>,
+    /// Exclusion circles as (cx, cy, r) that particles may not stick inside
+    obstacles: Vec<(f64, f64, f64)>,
+    seed: u64,
     rng: ChaCha8Rng,
 }
 
@@ -142,7 +92,8 @@ impl DendriteGenerator {
         min_move_distance=2.0,
         seed_points=None,
         branching_style="radial",
-        seed=None
+        seed=None,
+        obstacles=None
     ))]
     fn new(
         width: f64,
@@ -153,8 +104,16 @@ impl DendriteGenerator {
         seed_points: Option<Vec<(f64, f64)>>,
         branching_style: &str,
         seed: Option<u64>,
+        obstacles: Option<Vec<(f64, f64, f64)>>,
     ) -> PyResult<Self> {
         let style = BranchingStyle::from_str(branching_style)?;
+        let obstacles = obstacles.unwrap_or_default();
+        if obstacles.iter().any(|&(_, _, r)| r < 0.0 || r.is_nan()) {
+            return Err(AxiArtError::InvalidParameter(
+                "obstacle radii must be non-negative".into(),
+            )
+            .into());
+        }
 
         // Initialize seed points based on branching style
         let seeds = if let Some(points) = seed_points {
@@ -166,13 +125,12 @@ impl DendriteGenerator {
                 BranchingStyle::Radial => vec![(width / 2.0, height / 2.0)],
             }
         };
+        check_outside_obstacles(&seeds, &obstacles)?;
 
         // Initialize RNG with seed or default
-        let rng = if let Some(s) = seed {
-            ChaCha8Rng::seed_from_u64(s)
-        } else {
-            ChaCha8Rng::from_entropy()
-        };
+        // Draw a seed from entropy when none is given, so it can be reported
+        let seed = resolve_seed(seed).unwrap_or_else(|| rand::thread_rng().gen());
+        let rng = ChaCha8Rng::seed_from_u64(seed);
 
         Ok(DendriteGenerator {
             width,
@@ -182,6 +140,8 @@ impl DendriteGenerator {
             min_move_distance,
             branching_style: style,
             seed_points: seeds,
+            obstacles,
+            seed,
             rng,
         })
     }
@@ -197,47 +157,196 @@ impl DendriteGenerator {
     /// # Arguments
     ///
     /// * `max_attempts` - Maximum random walk attempts per particle (default: 1000)
-    #[pyo3(signature = (max_attempts=1000))]
+    /// * `should_cancel` - Optional callable polled every 100 particles; when
+    ///   it returns True, growth stops and the tree built so far is returned
+    #[pyo3(signature = (max_attempts=1000, should_cancel=None))]
     fn generate(
         &mut self,
+        py: Python<'_>,
+        max_attempts: usize,
+        should_cancel: Option<PyObject>,
+    ) -> PyResult<Tree> {
+        let (seeds, particles) = (self.seed_points.clone(), self.num_particles);
+        py.allow_threads(|| {
+            self.grow(
+                seeds,
+                Vec::new(),
+                particles,
+                max_attempts,
+                should_cancel.as_ref(),
+            )
+        })
+    }
+
+    /// Continue growing an existing aggregate
+    ///
+    /// Picks up from a tree returned by `generate` or `generate_from` (or
+    /// several concatenated, to merge separately seeded clusters): every
+    /// supplied point is part of the aggregate that new particles stick to,
+    /// and the supplied points and lines are returned unchanged at the front
+    /// of the extended tree. Points inside an obstacle are rejected, as for
+    /// seed points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Nodes of the existing tree
+    /// * `lines` - Branches of the existing tree
+    /// * `additional_particles` - Number of particles to add
+    /// * `max_attempts` - Maximum random walk attempts per particle (default: 1000)
+    /// * `should_cancel` - Optional callable polled every 100 particles
+    #[pyo3(signature = (points, lines, additional_particles, max_attempts=1000, should_cancel=None))]
+    fn generate_from(
+        &mut self,
+        py: Python<'_>,
+        points: Vec<(f64, f64)>,
+        lines: Vec<((f64, f64), (f64, f64))>,
+        additional_particles: usize,
         max_attempts: usize,
-    ) -> PyResult<(Vec<(f64, f64)>, Vec<((f64, f64), (f64, f64))>)> {
-        let mut points = self.seed_points.clone();
-        let mut lines = Vec::new();
+        should_cancel: Option<PyObject>,
+    ) -> PyResult<Tree> {
+        if points.is_empty() {
+            return Err(AxiArtError::InvalidParameter(
+                "points must contain at least one node to grow from".into(),
+            )
+            .into());
+        }
+        check_outside_obstacles(&points, &self.obstacles)?;
+        py.allow_threads(|| {
+            self.grow(
+                points,
+                lines,
+                additional_particles,
+                max_attempts,
+                should_cancel.as_ref(),
+            )
+        })
+    }
+
+    /// Seed points evenly spaced around a circle
+    ///
+    /// Returns `n` points on the circle of radius `r` about (cx, cy), the
+    /// first at angle 0, ready to pass as `seed_points` (e.g. for crystals
+    /// radiating from a ring).
+    #[staticmethod]
+    #[pyo3(signature = (cx, cy, r, n=36))]
+    fn seeds_on_circle(cx: f64, cy: f64, r: f64, n: usize) -> PyResult<Vec<(f64, f64)>> {
+        if n == 0 {
+            return Err(AxiArtError::InvalidParameter("n must be positive".into()).into());
+        }
+        Ok((0..n)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / n as f64;
+                (cx + r * angle.cos(), cy + r * angle.sin())
+            })
+            .collect())
+    }
+
+    /// Seed points evenly spaced along a line segment
+    ///
+    /// Returns `n` points from `p0` to `p1`, both ends included (a single
+    /// point sits at the midpoint), ready to pass as `seed_points`.
+    #[staticmethod]
+    #[pyo3(signature = (p0, p1, n=20))]
+    fn seeds_on_line(p0: (f64, f64), p1: (f64, f64), n: usize) -> PyResult<Vec<(f64, f64)>> {
+        if n == 0 {
+            return Err(AxiArtError::InvalidParameter("n must be positive".into()).into());
+        }
+        Ok((0..n)
+            .map(|i| {
+                let t = if n == 1 {
+                    0.5
+                } else {
+                    i as f64 / (n - 1) as f64
+                };
+                (p0.0 + (p1.0 - p0.0) * t, p0.1 + (p1.1 - p0.1) * t)
+            })
+            .collect())
+    }
+
+    /// Get the width of the canvas
+    #[getter]
+    fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Get the height of the canvas
+    #[getter]
+    fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the seed in use, including one drawn from entropy
+    ///
+    /// Pass it back as `seed` to a new generator to reproduce its first
+    /// result; later calls continue the same random stream.
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
 
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
+}
+
+impl DendriteGenerator {
+    /// DLA growth loop behind `generate` and `generate_from`, run without
+    /// the GIL
+    ///
+    /// Adds `num_particles` particles to the aggregate made of `points`,
+    /// appending the new nodes and branches to `points` and `lines`.
+    fn grow(
+        &mut self,
+        mut points: Vec<(f64, f64)>,
+        mut lines: Vec<((f64, f64), (f64, f64))>,
+        num_particles: usize,
+        max_attempts: usize,
+        should_cancel: Option<&PyObject>,
+    ) -> PyResult<Tree> {
         // Create spatial grid hash with cell size = attraction distance
         // This ensures nearest neighbor is always in 3x3 cell neighborhood
-        let mut grid = SpatialGrid::new(self.attraction_distance);
+        let mut grid = SpatialHash::new(self.attraction_distance);
 
-        // Insert seed points into spatial grid
-        for (idx, &(x, y)) in self.seed_points.iter().enumerate() {
-            grid.insert(x, y, idx);
+        // Insert the starting aggregate into spatial grid
+        for &(x, y) in &points {
+            grid.insert(x, y);
         }
 
         // DLA algorithm: add particles one by one
-        for particle_idx in 0..self.num_particles {
+        for particle_idx in 0..num_particles {
             let mut particle_pos = self.get_random_particle_position();
 
             // Random walk until particle sticks or exceeds max attempts
             for _ in 0..max_attempts {
+                // Particles never stick inside an obstacle; one that spawned
+                // or wandered into one starts over
+                if in_obstacle(particle_pos, &self.obstacles) {
+                    particle_pos = self.get_random_particle_position();
+                    continue;
+                }
+
                 // O(1) nearest neighbor search using spatial grid hash
-                if let Some((nearest_idx, dist_sq)) =
-                    grid.find_nearest(particle_pos.0, particle_pos.1, &points)
+                if let Some((nearest_idx, _)) =
+                    grid.nearest_within(particle_pos.0, particle_pos.1, self.attraction_distance)
                 {
-                    let distance = dist_sq.sqrt();
-
-                    if distance < self.attraction_distance {
-                        // Particle sticks to tree
-                        let nearest_pos = points[nearest_idx];
-                        let new_idx = points.len();
+                    // Particle sticks to tree
+                    let nearest_pos = points[nearest_idx];
 
-                        points.push(particle_pos);
-                        lines.push((nearest_pos, particle_pos));
+                    points.push(particle_pos);
+                    lines.push((nearest_pos, particle_pos));
 
-                        // Insert into spatial grid - O(1) operation
-                        grid.insert(particle_pos.0, particle_pos.1, new_idx);
-                        break;
-                    }
+                    // Insert into spatial grid - O(1) operation
+                    grid.insert(particle_pos.0, particle_pos.1);
+                    break;
                 }
 
                 // Continue random walk
@@ -253,33 +362,19 @@ impl DendriteGenerator {
                 }
             }
 
+            if (particle_idx + 1) % CANCEL_POLL_INTERVAL == 0 && cancel::requested(should_cancel)? {
+                break;
+            }
+
             // Progress indicator every 500 particles
             if (particle_idx + 1) % 500 == 0 {
-                println!(
-                    "Generated {}/{} particles",
-                    particle_idx + 1,
-                    self.num_particles
-                );
+                println!("Generated {}/{} particles", particle_idx + 1, num_particles);
             }
         }
 
         Ok((points, lines))
     }
 
-    /// Get the width of the canvas
-    #[getter]
-    fn width(&self) -> f64 {
-        self.width
-    }
-
-    /// Get the height of the canvas
-    #[getter]
-    fn height(&self) -> f64 {
-        self.height
-    }
-}
-
-impl DendriteGenerator {
     /// Get a random particle starting position based on branching style
     fn get_random_particle_position(&mut self) -> (f64, f64) {
         match self.branching_style {
@@ -335,3 +430,32 @@ impl DendriteGenerator {
         (new_x, new_y)
     }
 }
+
+/// Whether a point lies strictly inside any (cx, cy, r) obstacle
+fn in_obstacle((x, y): (f64, f64), obstacles: &[(f64, f64, f64)]) -> bool {
+    obstacles
+        .iter()
+        .any(|&(cx, cy, r)| (x - cx).powi(2) + (y - cy).powi(2) < r * r)
+}
+
+/// Reject aggregate points inside an obstacle, which no particle could
+/// reach to stick to
+fn check_outside_obstacles(
+    points: &[(f64, f64)],
+    obstacles: &[(f64, f64, f64)],
+) -> Result<(), AxiArtError> {
+    match points.iter().find(|&&p| in_obstacle(p, obstacles)) {
+        Some(&(x, y)) => Err(AxiArtError::InvalidParameter(format!(
+            "seed point ({}, {}) lies inside an obstacle",
+            x, y
+        ))),
+        None => Ok(()),
+    }
+}
+
+impl Generator for DendriteGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        Python::with_gil(|py| self.generate(py, 1000, None))
+            .map(|(_, lines)| Paths::from_segments(lines))
+    }
+}
@@ -0,0 +1,130 @@
+//! Distance metrics shared by the cell-based generators
+//!
+//! One `Metric` is used wherever a generator asks "which feature point is
+//! nearest", such as Voronoi site assignment and relaxation. Generators that
+//! take the same metric name and `p` therefore produce matching cell shapes.
+
+use crate::error::AxiArtError;
+use pyo3::prelude::*;
+
+/// Distance metric for nearest-point queries
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Metric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+    /// Generalized p-norm; p = 1 is Manhattan, p = 2 Euclidean and p → ∞
+    /// approaches Chebyshev
+    Minkowski(f64),
+}
+
+impl Metric {
+    /// Parse a metric name; `p` is only used by "minkowski" and must be >= 1
+    pub(crate) fn from_str(s: &str, p: f64) -> PyResult<Self> {
+        match s.to_lowercase().as_str() {
+            "euclidean" => Ok(Metric::Euclidean),
+            "manhattan" => Ok(Metric::Manhattan),
+            "chebyshev" => Ok(Metric::Chebyshev),
+            "minkowski" => {
+                if p < 1.0 {
+                    return Err(
+                        AxiArtError::InvalidParameter("Minkowski p must be >= 1".into()).into(),
+                    );
+                }
+                Ok(Metric::Minkowski(p))
+            }
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid metric. Use 'euclidean', 'manhattan', 'chebyshev', or 'minkowski'".into(),
+            )
+            .into()),
+        }
+    }
+
+    /// Monotonic stand-in for the distance, cheap enough for comparisons
+    ///
+    /// Skips the final root (square root for Euclidean, p-th root for
+    /// Minkowski), which doesn't change which point is nearest.
+    #[inline]
+    pub(crate) fn compare_key(&self, dx: f64, dy: f64) -> f64 {
+        let (dx, dy) = (dx.abs(), dy.abs());
+        match *self {
+            Metric::Euclidean => dx * dx + dy * dy,
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+            Metric::Minkowski(p) => dx.powf(p) + dy.powf(p),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The true distance, undoing the root `compare_key` skips
+    fn distance(metric: Metric, dx: f64, dy: f64) -> f64 {
+        let key = metric.compare_key(dx, dy);
+        match metric {
+            Metric::Euclidean => key.sqrt(),
+            Metric::Minkowski(p) => key.powf(1.0 / p),
+            _ => key,
+        }
+    }
+
+    const OFFSETS: [(f64, f64); 6] = [
+        (3.0, 4.0),
+        (-3.0, 4.0),
+        (0.0, -2.5),
+        (1.0, 1.0),
+        (-6.0, -0.5),
+        (0.0, 0.0),
+    ];
+
+    #[test]
+    fn reference_distances() {
+        let cases = [
+            (Metric::Euclidean, 5.0),
+            (Metric::Manhattan, 7.0),
+            (Metric::Chebyshev, 4.0),
+            (Metric::Minkowski(3.0), 91.0_f64.cbrt()),
+        ];
+        for (metric, expected) in cases {
+            assert!((distance(metric, 3.0, 4.0) - expected).abs() < 1e-12);
+            assert!((distance(metric, -4.0, -3.0) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn minkowski_matches_named_metrics() {
+        for (dx, dy) in OFFSETS {
+            let manhattan = distance(Metric::Manhattan, dx, dy);
+            let euclidean = distance(Metric::Euclidean, dx, dy);
+            let chebyshev = distance(Metric::Chebyshev, dx, dy);
+
+            assert!((distance(Metric::Minkowski(1.0), dx, dy) - manhattan).abs() < 1e-12);
+            assert!((distance(Metric::Minkowski(2.0), dx, dy) - euclidean).abs() < 1e-12);
+            assert!((distance(Metric::Minkowski(200.0), dx, dy) - chebyshev).abs() < 0.05);
+
+            // Every p-norm lies between Chebyshev and Manhattan
+            assert!(chebyshev <= euclidean && euclidean <= manhattan);
+        }
+    }
+
+    #[test]
+    fn compare_key_orders_like_distance() {
+        let metrics = [
+            Metric::Euclidean,
+            Metric::Manhattan,
+            Metric::Chebyshev,
+            Metric::Minkowski(1.5),
+        ];
+        for metric in metrics {
+            for a in OFFSETS {
+                for b in OFFSETS {
+                    let by_distance = distance(metric, a.0, a.1) < distance(metric, b.0, b.1);
+                    let by_key = metric.compare_key(a.0, a.1) < metric.compare_key(b.0, b.1);
+                    assert_eq!(by_distance, by_key, "{:?} {:?} {:?}", metric, a, b);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Error types raised to Python
+//!
+//! Every failure the crate reports is an `AxiArtError`, which converts into
+//! one of the exception classes below. They all derive from `ValueError`, so
+//! existing `except ValueError` handlers keep working while callers that need
+//! to branch on the kind of failure can catch the specific subclass.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::fmt;
+
+/// Python exception classes, registered on the `axiart_core` module
+pub mod exceptions {
+    use super::*;
+
+    create_exception!(
+        axiart_core,
+        AxiArtError,
+        PyValueError,
+        "Base class for all axiart_core errors"
+    );
+    create_exception!(
+        axiart_core,
+        InvalidOptionError,
+        AxiArtError,
+        "Unknown or unsupported option name, such as a preset, field type or metric"
+    );
+    create_exception!(
+        axiart_core,
+        InvalidParameterError,
+        AxiArtError,
+        "Parameter value out of range or inconsistent with other arguments"
+    );
+    create_exception!(
+        axiart_core,
+        OutOfBoundsError,
+        AxiArtError,
+        "Geometry that falls outside the canvas"
+    );
+}
+
+/// A failure reported to Python, carrying its message
+#[derive(Debug, Clone, PartialEq)]
+pub enum AxiArtError {
+    /// Unknown or unsupported option name (preset, field type, tile type,
+    /// metric, method, axis, ...)
+    InvalidOption(String),
+    /// Parameter value out of range or inconsistent with other arguments
+    InvalidParameter(String),
+    /// Input geometry outside the canvas
+    OutOfBounds(String),
+}
+
+impl fmt::Display for AxiArtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AxiArtError::InvalidOption(msg)
+            | AxiArtError::InvalidParameter(msg)
+            | AxiArtError::OutOfBounds(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for AxiArtError {}
+
+impl From<AxiArtError> for PyErr {
+    fn from(err: AxiArtError) -> PyErr {
+        match err {
+            AxiArtError::InvalidOption(msg) => exceptions::InvalidOptionError::new_err(msg),
+            AxiArtError::InvalidParameter(msg) => exceptions::InvalidParameterError::new_err(msg),
+            AxiArtError::OutOfBounds(msg) => exceptions::OutOfBoundsError::new_err(msg),
+        }
+    }
+}
+
+/// Add the exception classes to the Python module
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add(
+        "AxiArtError",
+        py.get_type_bound::<exceptions::AxiArtError>(),
+    )?;
+    m.add(
+        "InvalidOptionError",
+        py.get_type_bound::<exceptions::InvalidOptionError>(),
+    )?;
+    m.add(
+        "InvalidParameterError",
+        py.get_type_bound::<exceptions::InvalidParameterError>(),
+    )?;
+    m.add(
+        "OutOfBoundsError",
+        py.get_type_bound::<exceptions::OutOfBoundsError>(),
+    )?;
+    Ok(())
+}
@@ -6,11 +6,19 @@
 //! - Efficient curl noise computation
 //! - Zero overhead loops
 
+use crate::error::AxiArtError;
+use crate::geometry::segments_cross;
+use crate::noise_core::perlin_2d_with_gradient;
+use crate::paths::{Bounds, Generator, PathArrays, Paths};
+use crate::seed::resolve_seed;
+use crate::threads;
+use noise::permutationtable::PermutationTable;
 use noise::{NoiseFn, Perlin};
 use pyo3::prelude::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::f64::consts::PI;
 
 /// Field types for flow field generation
@@ -32,13 +40,53 @@ impl FieldType {
             "radial" => Ok(FieldType::Radial),
             "spiral" => Ok(FieldType::Spiral),
             "waves" => Ok(FieldType::Waves),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(
-                "Invalid field type. Use 'noise', 'radial', 'spiral', or 'waves'",
-            )),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid field type. Use 'noise', 'radial', 'spiral', or 'waves'".into(),
+            )
+            .into()),
         }
     }
 }
 
+/// What a streamline does when it reaches the canvas edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Boundary {
+    Stop,
+    Reflect,
+    Wrap,
+}
+
+impl Boundary {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s.to_lowercase().as_str() {
+            "stop" => Ok(Boundary::Stop),
+            "reflect" => Ok(Boundary::Reflect),
+            "wrap" => Ok(Boundary::Wrap),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid boundary. Use 'stop', 'reflect', or 'wrap'".into(),
+            )
+            .into()),
+        }
+    }
+}
+
+/// Mirror a coordinate that stepped past 0 or `max` back inside the range
+///
+/// Flips `sign`, the direction the tracer applies to this velocity
+/// component, whenever it reflects, so later steps keep heading inward
+/// instead of being pushed back out by the same field.
+fn reflect(v: f64, max: f64, sign: &mut f64) -> f64 {
+    if v < 0.0 {
+        *sign = -*sign;
+        (-v).min(max)
+    } else if v > max {
+        *sign = -*sign;
+        (2.0 * max - v).max(0.0)
+    } else {
+        v
+    }
+}
+
 /// High-performance Flow Field Generator
 ///
 /// Generates organic flowing patterns by tracing particles through vector fields.
@@ -54,6 +102,7 @@ pub struct FlowFieldGenerator {
     scale: f64,
     seed: u32,
     noise: Perlin,
+    perm: PermutationTable,
 }
 
 #[pymethods]
@@ -74,7 +123,9 @@ impl FlowFieldGenerator {
         seed: Option<u32>,
     ) -> PyResult<Self> {
         let ftype = FieldType::from_str(field_type)?;
-        let actual_seed = seed.unwrap_or_else(|| rand::thread_rng().gen());
+        let actual_seed = resolve_seed(seed.map(u64::from))
+            .map(|s| s as u32)
+            .unwrap_or_else(|| rand::thread_rng().gen());
         let noise = Perlin::new(actual_seed);
 
         Ok(FlowFieldGenerator {
@@ -84,6 +135,7 @@ impl FlowFieldGenerator {
             scale,
             seed: actual_seed,
             noise,
+            perm: PermutationTable::new(actual_seed),
         })
     }
 
@@ -92,53 +144,154 @@ impl FlowFieldGenerator {
     /// Returns list of paths, where each path is a list of (x, y) points
     ///
     /// This method uses parallel processing for massive speedup on multi-core systems.
-    #[pyo3(signature = (num_lines=100, steps=200, step_size=1.0, parallel=true))]
+    ///
+    /// With `stop_on_self_intersection`, a streamline ends as soon as its next
+    /// step would cross its own earlier path, so strongly rotating fields give
+    /// clean simple curves instead of overlapping scribbles.
+    ///
+    /// `boundary` sets what happens at the canvas edge: "stop" ends the
+    /// streamline, "reflect" mirrors the step back inside (flipping the
+    /// velocity component normal to the wall) and "wrap" re-enters from the
+    /// opposite edge, starting a new polyline there so no stroke crosses the
+    /// page. A wrapped streamline can therefore yield several paths.
+    ///
+    /// `length_variation` (0 to 1) gives each streamline its own step count,
+    /// `steps` scaled by a seeded random factor between 1 - variation and 1,
+    /// so long and short lines mix into a layered look. At 0.0 every line
+    /// gets the full `steps` and the output is unchanged.
+    ///
+    /// `time` samples the noise field in 3D at (x / scale, y / scale, time),
+    /// so sweeping it across frames makes the flow evolve smoothly: a change
+    /// of 1.0 alters the field about as much as moving `scale` across the
+    /// page. Left as None, the static 2D field is used. Other field types
+    /// ignore it.
+    ///
+    /// With `return_magnitude`, each path comes back as (path, value) where
+    /// value is the mean field magnitude |v| over its points, ready for
+    /// `magnitude_to_passes`. Noise, radial and spiral fields are unit
+    /// length almost everywhere, so the values mostly vary for waves.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
+    #[pyo3(signature = (
+        num_lines=100,
+        steps=200,
+        step_size=1.0,
+        parallel=true,
+        stop_on_self_intersection=false,
+        boundary="stop",
+        length_variation=0.0,
+        time=None,
+        return_magnitude=false
+    ))]
     fn generate_streamlines(
         &self,
+        py: Python<'_>,
         num_lines: usize,
         steps: usize,
         step_size: f64,
         parallel: bool,
-    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
-        let mut rng = ChaCha8Rng::seed_from_u64(self.seed as u64);
-
-        // Generate random starting positions
-        let start_positions: Vec<(f64, f64)> = (0..num_lines)
-            .map(|_| {
-                (
-                    rng.gen::<f64>() * self.width,
-                    rng.gen::<f64>() * self.height,
-                )
-            })
-            .collect();
-
-        if parallel {
-            // Parallel generation - massive speedup!
-            Ok(start_positions
-                .par_iter()
-                .filter_map(|&start_pos| self.trace_streamline(start_pos, steps, step_size))
-                .collect())
+        stop_on_self_intersection: bool,
+        boundary: &str,
+        length_variation: f64,
+        time: Option<f64>,
+        return_magnitude: bool,
+    ) -> PyResult<PyObject> {
+        let boundary = Boundary::from_str(boundary)?;
+        let starts = self.streamline_starts(num_lines, steps, length_variation)?;
+        let paths = self.trace_streamlines(
+            &starts,
+            step_size,
+            parallel,
+            stop_on_self_intersection,
+            boundary,
+            time,
+        );
+
+        if return_magnitude {
+            let with_magnitude: Vec<_> = paths
+                .into_iter()
+                .map(|path| {
+                    let magnitude = self.mean_magnitude(&path, time);
+                    (path, magnitude)
+                })
+                .collect();
+            Ok(with_magnitude.into_py(py))
         } else {
-            // Sequential generation
-            Ok(start_positions
-                .iter()
-                .filter_map(|&start_pos| self.trace_streamline(start_pos, steps, step_size))
-                .collect())
+            Ok(paths.into_py(py))
         }
     }
 
+    /// Generate streamlines as NumPy arrays
+    ///
+    /// Same paths as `generate_streamlines`, returned as `(coords, offsets)`:
+    /// `coords` is an (n, 2) array of every point and path i is
+    /// `coords[offsets[i]:offsets[i + 1]]`. Avoids building a Python tuple per
+    /// point for large outputs.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
+    #[pyo3(signature = (
+        num_lines=100,
+        steps=200,
+        step_size=1.0,
+        parallel=true,
+        stop_on_self_intersection=false,
+        boundary="stop",
+        length_variation=0.0,
+        time=None
+    ))]
+    fn generate_streamlines_array<'py>(
+        &self,
+        py: Python<'py>,
+        num_lines: usize,
+        steps: usize,
+        step_size: f64,
+        parallel: bool,
+        stop_on_self_intersection: bool,
+        boundary: &str,
+        length_variation: f64,
+        time: Option<f64>,
+    ) -> PyResult<PathArrays<'py>> {
+        let boundary = Boundary::from_str(boundary)?;
+        let starts = self.streamline_starts(num_lines, steps, length_variation)?;
+        let paths = self.trace_streamlines(
+            &starts,
+            step_size,
+            parallel,
+            stop_on_self_intersection,
+            boundary,
+            time,
+        );
+        Ok(Paths(paths).into_arrays(py))
+    }
+
     /// Generate curl noise streamlines (divergence-free flow)
     ///
     /// Curl noise creates smooth, swirling patterns with no sources or sinks.
     /// Much faster than Python due to native noise evaluation.
-    #[pyo3(signature = (num_lines=100, steps=200, step_size=1.0, parallel=true))]
+    /// `stop_on_self_intersection` and `boundary` work as in
+    /// `generate_streamlines`.
+    #[pyo3(signature = (
+        num_lines=100,
+        steps=200,
+        step_size=1.0,
+        parallel=true,
+        stop_on_self_intersection=false,
+        boundary="stop"
+    ))]
     fn generate_curl_noise_lines(
         &self,
         num_lines: usize,
         steps: usize,
         step_size: f64,
         parallel: bool,
+        stop_on_self_intersection: bool,
+        boundary: &str,
     ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let boundary = Boundary::from_str(boundary)?;
         let mut rng = ChaCha8Rng::seed_from_u64(self.seed as u64);
 
         let start_positions: Vec<(f64, f64)> = (0..num_lines)
@@ -151,18 +304,73 @@ impl FlowFieldGenerator {
             .collect();
 
         if parallel {
-            Ok(start_positions
-                .par_iter()
-                .filter_map(|&start_pos| self.trace_curl_noise(start_pos, steps, step_size))
-                .collect())
+            Ok(threads::install(|| {
+                start_positions
+                    .par_iter()
+                    .flat_map_iter(|&start_pos| {
+                        self.trace_curl_noise(
+                            start_pos,
+                            steps,
+                            step_size,
+                            stop_on_self_intersection,
+                            boundary,
+                        )
+                    })
+                    .collect()
+            }))
         } else {
             Ok(start_positions
                 .iter()
-                .filter_map(|&start_pos| self.trace_curl_noise(start_pos, steps, step_size))
+                .flat_map(|&start_pos| {
+                    self.trace_curl_noise(
+                        start_pos,
+                        steps,
+                        step_size,
+                        stop_on_self_intersection,
+                        boundary,
+                    )
+                })
                 .collect())
         }
     }
 
+    /// Generate curl noise streamlines as NumPy arrays
+    ///
+    /// Same paths as `generate_curl_noise_lines`, in the `(coords, offsets)`
+    /// layout of `generate_streamlines_array`.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
+    #[pyo3(signature = (
+        num_lines=100,
+        steps=200,
+        step_size=1.0,
+        parallel=true,
+        stop_on_self_intersection=false,
+        boundary="stop"
+    ))]
+    fn generate_curl_noise_lines_array<'py>(
+        &self,
+        py: Python<'py>,
+        num_lines: usize,
+        steps: usize,
+        step_size: f64,
+        parallel: bool,
+        stop_on_self_intersection: bool,
+        boundary: &str,
+    ) -> PyResult<PathArrays<'py>> {
+        let paths = self.generate_curl_noise_lines(
+            num_lines,
+            steps,
+            step_size,
+            parallel,
+            stop_on_self_intersection,
+            boundary,
+        )?;
+        Ok(Paths(paths).into_arrays(py))
+    }
+
     /// Generate grid visualization of the vector field
     #[pyo3(signature = (grid_spacing=10.0, arrow_length=5.0))]
     fn generate_grid_visualization(
@@ -177,7 +385,7 @@ impl FlowFieldGenerator {
             let mut y = 0.0;
             while y <= self.height {
                 // Get field vector
-                let (dx, dy) = self.get_field_vector(x, y);
+                let (dx, dy) = self.get_field_vector(x, y, None);
 
                 // Normalize and scale
                 let magnitude = (dx * dx + dy * dy).sqrt() + 0.001;
@@ -221,16 +429,126 @@ impl FlowFieldGenerator {
     fn height(&self) -> f64 {
         self.height
     }
+
+    /// Get the seed in use, including one drawn from entropy
+    ///
+    /// Pass it back as `seed` to reproduce a result you liked.
+    #[getter]
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl FlowFieldGenerator {
+    /// Seeded start positions and step counts of `generate_streamlines`
+    ///
+    /// `length_variation` gives each line its own step count, as described
+    /// there.
+    fn streamline_starts(
+        &self,
+        num_lines: usize,
+        steps: usize,
+        length_variation: f64,
+    ) -> PyResult<Vec<((f64, f64), usize)>> {
+        if !(0.0..=1.0).contains(&length_variation) {
+            return Err(AxiArtError::InvalidParameter(
+                "length_variation must be between 0 and 1".into(),
+            )
+            .into());
+        }
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed as u64);
+
+        // Generate random starting positions
+        let start_positions: Vec<(f64, f64)> = (0..num_lines)
+            .map(|_| {
+                (
+                    rng.gen::<f64>() * self.width,
+                    rng.gen::<f64>() * self.height,
+                )
+            })
+            .collect();
+
+        // Per-line step counts, drawn after the positions so those match the
+        // unvaried output
+        let line_steps = (0..num_lines).map(|_| {
+            if length_variation > 0.0 {
+                let factor = 1.0 - length_variation * rng.gen::<f64>();
+                (steps as f64 * factor).round() as usize
+            } else {
+                steps
+            }
+        });
+
+        Ok(start_positions.into_iter().zip(line_steps).collect())
+    }
+
+    /// Trace one streamline (or several, when wrapped) from each start
+    fn trace_streamlines(
+        &self,
+        starts: &[((f64, f64), usize)],
+        step_size: f64,
+        parallel: bool,
+        stop_on_self_intersection: bool,
+        boundary: Boundary,
+        time: Option<f64>,
+    ) -> Vec<Vec<(f64, f64)>> {
+        let trace = |&(start_pos, steps): &((f64, f64), usize)| {
+            self.trace_streamline(
+                start_pos,
+                steps,
+                step_size,
+                stop_on_self_intersection,
+                boundary,
+                time,
+            )
+        };
+
+        if parallel {
+            // Parallel generation - massive speedup!
+            threads::install(|| starts.par_iter().flat_map_iter(trace).collect())
+        } else {
+            // Sequential generation
+            starts.iter().flat_map(trace).collect()
+        }
+    }
+
+    /// Mean field magnitude |v| over the points of a path
+    fn mean_magnitude(&self, path: &[(f64, f64)], time: Option<f64>) -> f64 {
+        let total: f64 = path
+            .iter()
+            .map(|&(x, y)| {
+                let (dx, dy) = self.get_field_vector(x, y, time);
+                (dx * dx + dy * dy).sqrt()
+            })
+            .sum();
+        total / path.len().max(1) as f64
+    }
+
     /// Get vector field value at position
+    ///
+    /// With a `time`, the noise field is sampled in 3D at that depth.
     #[inline]
-    fn get_field_vector(&self, x: f64, y: f64) -> (f64, f64) {
+    fn get_field_vector(&self, x: f64, y: f64, time: Option<f64>) -> (f64, f64) {
         match self.field_type {
             FieldType::Noise => {
                 // Perlin noise-based field
-                let noise_val = self.noise.get([x / self.scale, y / self.scale]);
+                let noise_val = match time {
+                    Some(t) => self.noise.get([x / self.scale, y / self.scale, t]),
+                    None => self.noise.get([x / self.scale, y / self.scale]),
+                };
                 let angle = noise_val * PI * 4.0;
                 (angle.cos(), angle.sin())
             }
@@ -272,21 +590,46 @@ impl FlowFieldGenerator {
         start: (f64, f64),
         steps: usize,
         step_size: f64,
-    ) -> Option<Vec<(f64, f64)>> {
+        stop_on_self_intersection: bool,
+        boundary: Boundary,
+        time: Option<f64>,
+    ) -> Vec<Vec<(f64, f64)>> {
+        let mut pieces = Vec::new();
         let mut path = vec![start];
         let (mut x, mut y) = start;
+        let mut visited = stop_on_self_intersection.then(|| SegmentGrid::new(step_size));
+        // Per-axis direction, flipped by each reflection off a wall
+        let mut sign = (1.0, 1.0);
 
         for _ in 0..steps {
             // Get vector field at current position
-            let (dx, dy) = self.get_field_vector(x, y);
+            let (dx, dy) = self.get_field_vector(x, y, time);
 
             // Update position
-            x += dx * step_size;
-            y += dy * step_size;
+            x += dx * sign.0 * step_size;
+            y += dy * sign.1 * step_size;
 
             // Check bounds
             if x < 0.0 || x > self.width || y < 0.0 || y > self.height {
-                break;
+                match boundary {
+                    Boundary::Stop => break,
+                    Boundary::Reflect => {
+                        x = reflect(x, self.width, &mut sign.0);
+                        y = reflect(y, self.height, &mut sign.1);
+                    }
+                    Boundary::Wrap => {
+                        x = x.rem_euclid(self.width);
+                        y = y.rem_euclid(self.height);
+                        pieces.push(std::mem::replace(&mut path, vec![(x, y)]));
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(grid) = visited.as_mut() {
+                if !grid.extend(path[path.len() - 1], (x, y)) {
+                    break;
+                }
             }
 
             path.push((x, y));
@@ -301,11 +644,9 @@ impl FlowFieldGenerator {
             }
         }
 
-        if path.len() > 2 {
-            Some(path)
-        } else {
-            None
-        }
+        pieces.push(path);
+        pieces.retain(|piece| piece.len() > 2);
+        pieces
     }
 
     /// Trace curl noise streamline
@@ -317,40 +658,157 @@ impl FlowFieldGenerator {
         start: (f64, f64),
         steps: usize,
         step_size: f64,
-    ) -> Option<Vec<(f64, f64)>> {
+        stop_on_self_intersection: bool,
+        boundary: Boundary,
+    ) -> Vec<Vec<(f64, f64)>> {
+        let mut pieces = Vec::new();
         let mut path = vec![start];
         let (mut x, mut y) = start;
-        const EPSILON: f64 = 0.1;
+        let mut visited = stop_on_self_intersection.then(|| SegmentGrid::new(step_size));
+        // Per-axis direction, flipped by each reflection off a wall
+        let mut sign = (1.0, 1.0);
 
         for _ in 0..steps {
             // Compute curl of noise field
             // curl(F) = (∂Fz/∂y - ∂Fy/∂z, ∂Fx/∂z - ∂Fz/∂x, ∂Fy/∂x - ∂Fx/∂y)
             // For 2D: curl = (∂noise/∂y, -∂noise/∂x)
+            let (_, grad_x, grad_y) =
+                perlin_2d_with_gradient(&self.perm, x / self.scale, y / self.scale);
 
-            let noise_x_plus = self.noise.get([(x + EPSILON) / self.scale, y / self.scale]);
-            let noise_x_minus = self.noise.get([(x - EPSILON) / self.scale, y / self.scale]);
-            let noise_y_plus = self.noise.get([x / self.scale, (y + EPSILON) / self.scale]);
-            let noise_y_minus = self.noise.get([x / self.scale, (y - EPSILON) / self.scale]);
-
-            // Compute gradient
-            let dx = (noise_y_plus - noise_y_minus) / (2.0 * EPSILON);
-            let dy = -(noise_x_plus - noise_x_minus) / (2.0 * EPSILON);
+            // Chain rule: noise is sampled at (x / scale, y / scale)
+            let dx = grad_y / self.scale;
+            let dy = -grad_x / self.scale;
 
             // Move particle
-            x += dx * step_size;
-            y += dy * step_size;
+            x += dx * sign.0 * step_size;
+            y += dy * sign.1 * step_size;
 
             if x < 0.0 || x > self.width || y < 0.0 || y > self.height {
-                break;
+                match boundary {
+                    Boundary::Stop => break,
+                    Boundary::Reflect => {
+                        x = reflect(x, self.width, &mut sign.0);
+                        y = reflect(y, self.height, &mut sign.1);
+                    }
+                    Boundary::Wrap => {
+                        x = x.rem_euclid(self.width);
+                        y = y.rem_euclid(self.height);
+                        pieces.push(std::mem::replace(&mut path, vec![(x, y)]));
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(grid) = visited.as_mut() {
+                if !grid.extend(path[path.len() - 1], (x, y)) {
+                    break;
+                }
             }
 
             path.push((x, y));
         }
 
-        if path.len() > 2 {
-            Some(path)
-        } else {
-            None
+        pieces.push(path);
+        pieces.retain(|piece| piece.len() > 2);
+        pieces
+    }
+}
+
+/// The segments of one streamline, bucketed by grid cell
+///
+/// Lets a tracer check each new step against only the nearby parts of the
+/// line drawn so far.
+struct SegmentGrid {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    segments: Vec<((f64, f64), (f64, f64))>,
+}
+
+impl SegmentGrid {
+    fn new(cell_size: f64) -> Self {
+        SegmentGrid {
+            cell_size: cell_size.abs().max(1e-6),
+            cells: HashMap::new(),
+            segments: Vec::new(),
         }
     }
+
+    /// Cells overlapped by the bounding box of a->b
+    fn cells_of(&self, a: (f64, f64), b: (f64, f64)) -> impl Iterator<Item = (i64, i64)> {
+        let cell = |v: f64| (v / self.cell_size).floor() as i64;
+        let (cx0, cx1) = (cell(a.0.min(b.0)), cell(a.0.max(b.0)));
+        let (cy0, cy1) = (cell(a.1.min(b.1)), cell(a.1.max(b.1)));
+        (cx0..=cx1).flat_map(move |cx| (cy0..=cy1).map(move |cy| (cx, cy)))
+    }
+
+    /// Add segment a->b unless it crosses an earlier segment, returning
+    /// whether it was added
+    ///
+    /// The previous segment shares `a` and is never counted as a crossing.
+    fn extend(&mut self, a: (f64, f64), b: (f64, f64)) -> bool {
+        let previous = self.segments.len().checked_sub(1);
+        let crosses = self.cells_of(a, b).any(|key| {
+            self.cells.get(&key).is_some_and(|bucket| {
+                bucket.iter().any(|&i| {
+                    let (c, d) = self.segments[i];
+                    Some(i) != previous && segments_cross(a, b, c, d)
+                })
+            })
+        });
+        if crosses {
+            return false;
+        }
+
+        let index = self.segments.len();
+        self.segments.push((a, b));
+        let keys: Vec<(i64, i64)> = self.cells_of(a, b).collect();
+        for key in keys {
+            self.cells.entry(key).or_default().push(index);
+        }
+        true
+    }
+}
+
+impl Generator for FlowFieldGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        let starts = self.streamline_starts(100, 200, 0.0)?;
+        Ok(Paths(self.trace_streamlines(
+            &starts,
+            1.0,
+            true,
+            false,
+            Boundary::Stop,
+            None,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn radial_generator() -> FlowFieldGenerator {
+        FlowFieldGenerator {
+            width: 100.0,
+            height: 100.0,
+            field_type: FieldType::Radial,
+            scale: 50.0,
+            seed: 0,
+            noise: Perlin::new(0),
+            perm: PermutationTable::new(0),
+        }
+    }
+
+    #[test]
+    fn reflected_streamline_moves_back_into_the_canvas() {
+        // The radial field points straight at the right wall from here
+        let flow = radial_generator();
+        let pieces = flow.trace_streamline((98.0, 50.0), 40, 1.0, false, Boundary::Reflect, None);
+
+        assert_eq!(pieces.len(), 1);
+        let path = &pieces[0];
+        assert_eq!(path.len(), 41, "the line should not stall at the wall");
+        assert!(path.iter().all(|&(x, _)| x <= 100.0));
+        assert!(path[path.len() - 1].0 < 70.0);
+    }
 }
@@ -0,0 +1,105 @@
+//! G-code export for CNC-style pen plotters
+//!
+//! Emits absolute-coordinate millimeter G-code with the pen raised and lowered
+//! on the Z axis: rapid (`G0`) moves between paths with the pen up and feed
+//! (`G1`) moves along each path with the pen down.
+
+use crate::error::AxiArtError;
+use crate::paths::Paths;
+use crate::svg::format_number;
+use pyo3::prelude::*;
+use std::fmt::Write;
+
+/// Render polylines as a G-code program
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points, in drawing order
+/// * `feed_rate` - Drawing speed in mm/min for pen-down moves
+/// * `pen_up_z` - Z height with the pen raised
+/// * `pen_down_z` - Z height with the pen touching the paper
+/// * `y_flip` - Mirror Y for machines with a bottom-left origin (the canvas
+///   origin is top-left), mapping y to `height - y`
+/// * `height` - Canvas height used by `y_flip`; required when flipping
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import reorder_paths, to_gcode
+///
+/// program = to_gcode(reorder_paths(paths), feed_rate=1500.0, y_flip=True, height=210.0)
+/// open("plot.gcode", "w").write(program)
+/// ```
+#[pyfunction]
+#[pyo3(signature = (
+    paths,
+    feed_rate=1000.0,
+    pen_up_z=5.0,
+    pen_down_z=0.0,
+    y_flip=false,
+    height=None
+))]
+pub fn to_gcode(
+    paths: Paths,
+    feed_rate: f64,
+    pen_up_z: f64,
+    pen_down_z: f64,
+    y_flip: bool,
+    height: Option<f64>,
+) -> PyResult<String> {
+    // Also rejects NaN, which fails every comparison
+    if !feed_rate.is_finite() || feed_rate <= 0.0 {
+        return Err(AxiArtError::InvalidParameter("feed_rate must be positive".into()).into());
+    }
+    let flip_height = flip_height(y_flip, height)?;
+
+    let map_y = |y: f64| flip_height.map_or(y, |height| height - y);
+    let up = format_number(pen_up_z);
+    let down = format_number(pen_down_z);
+    let feed = format_number(feed_rate);
+
+    let mut gcode = String::new();
+    // Writing to a String cannot fail
+    let _ = writeln!(gcode, "; Generated by axiart");
+    let _ = writeln!(gcode, "G21 ; millimeters");
+    let _ = writeln!(gcode, "G90 ; absolute coordinates");
+    let _ = writeln!(gcode, "G0 Z{up}");
+
+    for path in paths.0.iter().filter(|p| !p.is_empty()) {
+        let (x, y) = path[0];
+        let _ = writeln!(
+            gcode,
+            "G0 X{} Y{}",
+            format_number(x),
+            format_number(map_y(y))
+        );
+        let _ = writeln!(gcode, "G1 Z{down} F{feed}");
+        for &(x, y) in &path[1..] {
+            let _ = writeln!(
+                gcode,
+                "G1 X{} Y{}",
+                format_number(x),
+                format_number(map_y(y))
+            );
+        }
+        let _ = writeln!(gcode, "G0 Z{up}");
+    }
+
+    let _ = writeln!(gcode, "M2");
+    Ok(gcode)
+}
+
+/// Height to mirror Y about, or None when not flipping
+///
+/// Flipping needs the real canvas height; guessing one would move every
+/// point of a differently sized canvas to the wrong place.
+pub(crate) fn flip_height(y_flip: bool, height: Option<f64>) -> PyResult<Option<f64>> {
+    if !y_flip {
+        return Ok(None);
+    }
+    match height {
+        Some(height) => Ok(Some(height)),
+        None => Err(
+            AxiArtError::InvalidParameter("height is required when y_flip is set".into()).into(),
+        ),
+    }
+}
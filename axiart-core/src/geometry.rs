@@ -0,0 +1,656 @@
+//! Shared polygon geometry primitives
+//!
+//! Point-in-polygon tests, centroids, polyline clipping (against polygons
+//! or rectangles) and segment merging used by the generators that fill, mask,
+//! trim or clean up their output.
+//! Polygons are vertex lists and may be given open or closed (first point
+//! repeated at the end). The point-in-polygon and orientation tests are also
+//! exposed to Python for compositions that need them directly.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_2;
+
+/// Line segment as ((x1, y1), (x2, y2))
+type Segment = ((f64, f64), (f64, f64));
+
+/// Even-odd point-in-polygon test
+pub(crate) fn point_in_polygon(x: f64, y: f64, polygon: &[(f64, f64)]) -> bool {
+    let n = polygon.len();
+    if n < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = n - 1;
+    for i in 0..n {
+        let (xi, yi) = polygon[i];
+        let (xj, yj) = polygon[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+/// Winding number of a closed vertex ring around a point
+///
+/// Counter-clockwise turns (in a y-up frame) count positive.
+pub(crate) fn winding_number(x: f64, y: f64, ring: &[(f64, f64)]) -> i32 {
+    let n = ring.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (x0, y0) = ring[i];
+        let (x1, y1) = ring[(i + 1) % n];
+        let side = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+        if y0 <= y {
+            if y1 > y && side > 0.0 {
+                winding += 1;
+            }
+        } else if y1 <= y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Signed area via the shoelace formula (positive for counter-clockwise
+/// vertices in a y-up frame)
+pub(crate) fn signed_area(polygon: &[(f64, f64)]) -> f64 {
+    let n = polygon.len();
+    if n < 3 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for i in 0..n {
+        let (x0, y0) = polygon[i];
+        let (x1, y1) = polygon[(i + 1) % n];
+        sum += x0 * y1 - x1 * y0;
+    }
+
+    sum / 2.0
+}
+
+/// Area centroid of a simple polygon
+///
+/// Falls back to the vertex average for degenerate (zero-area) input.
+pub(crate) fn polygon_centroid(polygon: &[(f64, f64)]) -> (f64, f64) {
+    let n = polygon.len();
+    if n == 0 {
+        return (0.0, 0.0);
+    }
+
+    let area = signed_area(polygon);
+    if area.abs() < 1e-12 {
+        let (sx, sy) = polygon
+            .iter()
+            .fold((0.0, 0.0), |(ax, ay), &(x, y)| (ax + x, ay + y));
+        return (sx / n as f64, sy / n as f64);
+    }
+
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+    for i in 0..n {
+        let (x0, y0) = polygon[i];
+        let (x1, y1) = polygon[(i + 1) % n];
+        let cross = x0 * y1 - x1 * y0;
+        cx += (x0 + x1) * cross;
+        cy += (y0 + y1) * cross;
+    }
+
+    (cx / (6.0 * area), cy / (6.0 * area))
+}
+
+/// Total length of a polyline
+pub(crate) fn polyline_length(line: &[(f64, f64)]) -> f64 {
+    line.windows(2)
+        .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+        .sum()
+}
+
+/// Distance from point p to segment a->b
+pub(crate) fn point_segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p.0 - a.0 - dx * t).hypot(p.1 - a.1 - dy * t)
+}
+
+/// Whether segments a->b and c->d cross at a single interior point
+///
+/// Touching endpoints and collinear overlaps do not count.
+pub(crate) fn segments_cross(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool {
+    let orient = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+    };
+    orient(a, b, c) * orient(a, b, d) < 0.0 && orient(c, d, a) * orient(c, d, b) < 0.0
+}
+
+/// Parameters `t` in (0, 1) where segment a->b crosses a polygon edge
+fn segment_polygon_crossings(a: (f64, f64), b: (f64, f64), polygon: &[(f64, f64)]) -> Vec<f64> {
+    let n = polygon.len();
+    let mut ts = Vec::new();
+    let (rx, ry) = (b.0 - a.0, b.1 - a.1);
+
+    for i in 0..n {
+        let p = polygon[i];
+        let q = polygon[(i + 1) % n];
+        let (sx, sy) = (q.0 - p.0, q.1 - p.1);
+
+        let denom = rx * sy - ry * sx;
+        if denom.abs() < 1e-12 {
+            continue; // Parallel or degenerate edge
+        }
+
+        let (qpx, qpy) = (p.0 - a.0, p.1 - a.1);
+        let t = (qpx * sy - qpy * sx) / denom;
+        let u = (qpx * ry - qpy * rx) / denom;
+        if t > 0.0 && t < 1.0 && (0.0..=1.0).contains(&u) {
+            ts.push(t);
+        }
+    }
+
+    ts
+}
+
+/// Point at parameter `t` along a->b, exact at the endpoints
+#[inline]
+fn lerp(a: (f64, f64), b: (f64, f64), t: f64) -> (f64, f64) {
+    if t <= 0.0 {
+        a
+    } else if t >= 1.0 {
+        b
+    } else {
+        (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+    }
+}
+
+/// Clip a polyline against a polygon
+///
+/// Keeps the portions inside the polygon (or outside, when `keep_inside`
+/// is false), splitting the polyline wherever it crosses the boundary.
+pub(crate) fn clip_polyline_to_polygon(
+    line: &[(f64, f64)],
+    polygon: &[(f64, f64)],
+    keep_inside: bool,
+) -> Vec<Vec<(f64, f64)>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<(f64, f64)> = Vec::new();
+
+    for w in line.windows(2) {
+        let (a, b) = (w[0], w[1]);
+
+        let mut ts = segment_polygon_crossings(a, b, polygon);
+        ts.push(0.0);
+        ts.push(1.0);
+        ts.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        ts.dedup_by(|x, y| (*x - *y).abs() < 1e-12);
+
+        for span in ts.windows(2) {
+            let (t0, t1) = (span[0], span[1]);
+            let (mx, my) = lerp(a, b, (t0 + t1) / 2.0);
+
+            if point_in_polygon(mx, my, polygon) == keep_inside {
+                let p0 = lerp(a, b, t0);
+                if current.last() != Some(&p0) {
+                    if current.len() > 1 {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                    current.push(p0);
+                }
+                current.push(lerp(a, b, t1));
+            } else if !current.is_empty() {
+                if current.len() > 1 {
+                    pieces.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+
+    pieces
+}
+
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+/// Axis-aligned clipping rectangle
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rect {
+    pub x0: f64,
+    pub y0: f64,
+    pub x1: f64,
+    pub y1: f64,
+}
+
+impl Rect {
+    /// Build a rectangle from any two opposite corners
+    pub(crate) fn new(x0: f64, y0: f64, x1: f64, y1: f64) -> Self {
+        Rect {
+            x0: x0.min(x1),
+            y0: y0.min(y1),
+            x1: x0.max(x1),
+            y1: y0.max(y1),
+        }
+    }
+
+    /// Cohen–Sutherland region code of a point
+    #[inline]
+    fn outcode(&self, x: f64, y: f64) -> u8 {
+        let mut code = INSIDE;
+        if x < self.x0 {
+            code |= LEFT;
+        } else if x > self.x1 {
+            code |= RIGHT;
+        }
+        if y < self.y0 {
+            code |= BOTTOM;
+        } else if y > self.y1 {
+            code |= TOP;
+        }
+        code
+    }
+
+    /// Clip a segment with Cohen–Sutherland, returning the visible part
+    pub(crate) fn clip_segment(
+        &self,
+        a: (f64, f64),
+        b: (f64, f64),
+    ) -> Option<((f64, f64), (f64, f64))> {
+        let (mut ax, mut ay) = a;
+        let (mut bx, mut by) = b;
+        let mut code_a = self.outcode(ax, ay);
+        let mut code_b = self.outcode(bx, by);
+
+        loop {
+            if code_a | code_b == INSIDE {
+                return Some(((ax, ay), (bx, by)));
+            }
+            if code_a & code_b != INSIDE {
+                return None;
+            }
+
+            // Move the outside endpoint onto the boundary it violates
+            let code_out = if code_a != INSIDE { code_a } else { code_b };
+            let (x, y) = if code_out & TOP != 0 {
+                (ax + (bx - ax) * (self.y1 - ay) / (by - ay), self.y1)
+            } else if code_out & BOTTOM != 0 {
+                (ax + (bx - ax) * (self.y0 - ay) / (by - ay), self.y0)
+            } else if code_out & RIGHT != 0 {
+                (self.x1, ay + (by - ay) * (self.x1 - ax) / (bx - ax))
+            } else {
+                (self.x0, ay + (by - ay) * (self.x0 - ax) / (bx - ax))
+            };
+
+            if code_out == code_a {
+                (ax, ay) = (x, y);
+                code_a = self.outcode(ax, ay);
+            } else {
+                (bx, by) = (x, y);
+                code_b = self.outcode(bx, by);
+            }
+        }
+    }
+}
+
+/// Clip a polyline to a rectangle
+///
+/// Each segment is clipped with Cohen–Sutherland; the polyline is split into
+/// separate pieces wherever it leaves the rectangle and re-enters.
+pub(crate) fn clip_polyline_to_rect(line: &[(f64, f64)], rect: &Rect) -> Vec<Vec<(f64, f64)>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<(f64, f64)> = Vec::new();
+
+    if line.len() == 1 {
+        let (x, y) = line[0];
+        if rect.outcode(x, y) == INSIDE {
+            pieces.push(vec![line[0]]);
+        }
+        return pieces;
+    }
+
+    for w in line.windows(2) {
+        match rect.clip_segment(w[0], w[1]) {
+            Some((p0, p1)) => {
+                if current.last() != Some(&p0) {
+                    if current.len() > 1 {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                    current.push(p0);
+                }
+                current.push(p1);
+            }
+            None => {
+                if current.len() > 1 {
+                    pieces.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+
+    pieces
+}
+
+/// Merge collinear, endpoint-adjacent segments into single long segments
+///
+/// Segments are chained through endpoints shared by exactly two segments (so
+/// junctions are preserved) as long as the continuation stays within
+/// `angle_tolerance` radians of the chain's direction and within
+/// `position_tolerance` of its line. Endpoints closer than
+/// `position_tolerance` are treated as shared.
+pub(crate) fn merge_collinear_segments(
+    segments: &[Segment],
+    angle_tolerance: f64,
+    position_tolerance: f64,
+) -> Vec<Segment> {
+    let key = |(x, y): (f64, f64)| {
+        (
+            (x / position_tolerance).round() as i64,
+            (y / position_tolerance).round() as i64,
+        )
+    };
+    let endpoint = |seg: usize, end: usize| {
+        if end == 0 {
+            segments[seg].0
+        } else {
+            segments[seg].1
+        }
+    };
+
+    let mut incident: HashMap<(i64, i64), Vec<(usize, usize)>> = HashMap::new();
+    for (idx, &(a, b)) in segments.iter().enumerate() {
+        incident.entry(key(a)).or_default().push((idx, 0));
+        incident.entry(key(b)).or_default().push((idx, 1));
+    }
+
+    let min_cos = angle_tolerance.cos();
+    let mut used = vec![false; segments.len()];
+    let mut merged = Vec::new();
+
+    for start_idx in 0..segments.len() {
+        if used[start_idx] {
+            continue;
+        }
+        used[start_idx] = true;
+
+        let (a, b) = segments[start_idx];
+        let length = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+        if length <= position_tolerance {
+            continue; // Degenerate fragment
+        }
+        let dir = ((b.0 - a.0) / length, (b.1 - a.1) / length);
+
+        // Extend forward from `b` (sign 1) and backward from `a` (sign -1)
+        let mut ends = [a, b];
+        for (slot, sign) in [(1, 1.0), (0, -1.0)] {
+            let mut current = start_idx;
+            loop {
+                let here = ends[slot];
+                let Some(list) = incident.get(&key(here)) else {
+                    break;
+                };
+                if list.len() != 2 {
+                    break; // Junction or dangling end
+                }
+
+                let Some(&(next, next_end)) = list.iter().find(|&&(s, _)| s != current) else {
+                    break;
+                };
+                if used[next] {
+                    break;
+                }
+
+                let far = endpoint(next, 1 - next_end);
+                let (fx, fy) = (far.0 - here.0, far.1 - here.1);
+                let step = (fx * fx + fy * fy).sqrt();
+                if step <= position_tolerance {
+                    used[next] = true;
+                    current = next;
+                    continue;
+                }
+
+                let cos = (fx * dir.0 + fy * dir.1) * sign / step;
+                let offset = (far.0 - a.0) * dir.1 - (far.1 - a.1) * dir.0;
+                if cos < min_cos || offset.abs() > position_tolerance {
+                    break;
+                }
+
+                used[next] = true;
+                ends[slot] = far;
+                current = next;
+            }
+        }
+
+        merged.push((ends[0], ends[1]));
+    }
+
+    merged
+}
+
+/// A segment's place on its line, for `remove_collinear_overlaps`
+///
+/// The line runs along the unit direction `dir` (pointing right, or up when
+/// vertical) at signed distance `offset` to the left of the origin, and the
+/// segment spans `t0..t1` along it.
+struct LinePlacement {
+    index: usize,
+    dir: (f64, f64),
+    angle: f64,
+    offset: f64,
+    t0: f64,
+    t1: f64,
+    reversed: bool,
+}
+
+/// Merge collinear segments that overlap, so retraced strokes are drawn once
+///
+/// Segments on the same line (within `tolerance`) whose extents along it
+/// overlap by more than `tolerance` are replaced by one segment spanning
+/// their union, in the place and direction of the earliest of them. Segments
+/// that only meet end to end, and all others, are returned unchanged and in
+/// order.
+pub(crate) fn remove_collinear_overlaps(segments: &[Segment], tolerance: f64) -> Vec<Segment> {
+    let length = |(a, b): Segment| ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+    let longest = segments.iter().map(|&s| length(s)).fold(0.0, f64::max);
+    if longest <= tolerance {
+        return segments.to_vec();
+    }
+    // Directions closer than this stay within `tolerance` of each other over
+    // the longest segment
+    let angle_tolerance = tolerance / longest;
+
+    let mut placements: Vec<LinePlacement> = Vec::new();
+    for (index, &(a, b)) in segments.iter().enumerate() {
+        let len = length((a, b));
+        if len <= tolerance {
+            continue; // Degenerate, nothing to overlap
+        }
+        let mut dir = ((b.0 - a.0) / len, (b.1 - a.1) / len);
+        let mut reversed = false;
+        if dir.0 < 0.0 {
+            dir = (-dir.0, -dir.1);
+            reversed = true;
+        }
+        // Fold nearly-downward directions onto upward so that vertical lines
+        // with opposite rounding land together
+        if dir.1.atan2(dir.0) < -FRAC_PI_2 + angle_tolerance {
+            dir = (-dir.0, -dir.1);
+            reversed = !reversed;
+        }
+        let (ta, tb) = (dir.0 * a.0 + dir.1 * a.1, dir.0 * b.0 + dir.1 * b.1);
+        placements.push(LinePlacement {
+            index,
+            dir,
+            angle: dir.1.atan2(dir.0),
+            offset: dir.0 * a.1 - dir.1 * a.0,
+            t0: ta.min(tb),
+            t1: ta.max(tb),
+            reversed,
+        });
+    }
+
+    let mut output: Vec<Option<Segment>> = segments.iter().copied().map(Some).collect();
+
+    // Group by angle, then by offset within each angle, chaining neighbors
+    // within tolerance so that values straddling a boundary stay together
+    let mut by_angle: Vec<usize> = (0..placements.len()).collect();
+    by_angle.sort_by(|&i, &j| placements[i].angle.total_cmp(&placements[j].angle));
+    for same_angle in
+        by_angle.chunk_by(|&i, &j| placements[j].angle - placements[i].angle <= angle_tolerance)
+    {
+        let mut by_offset = same_angle.to_vec();
+        by_offset.sort_by(|&i, &j| placements[i].offset.total_cmp(&placements[j].offset));
+        for same_line in
+            by_offset.chunk_by(|&i, &j| placements[j].offset - placements[i].offset <= tolerance)
+        {
+            let mut line = same_line.to_vec();
+            line.sort_by(|&i, &j| placements[i].t0.total_cmp(&placements[j].t0));
+
+            // Sweep along the line, collecting runs of overlapping extents
+            let mut run_start = 0;
+            let mut run_end = placements[line[0]].t1;
+            for k in 1..=line.len() {
+                if k < line.len() && placements[line[k]].t0 < run_end - tolerance {
+                    run_end = run_end.max(placements[line[k]].t1);
+                    continue;
+                }
+
+                let run = &line[run_start..k];
+                if run.len() > 1 {
+                    let first = run
+                        .iter()
+                        .map(|&i| &placements[i])
+                        .min_by_key(|p| p.index)
+                        .unwrap();
+                    let at = |t: f64| {
+                        (
+                            t * first.dir.0 - first.offset * first.dir.1,
+                            t * first.dir.1 + first.offset * first.dir.0,
+                        )
+                    };
+                    let (start, end) = (at(placements[run[0]].t0), at(run_end));
+                    for &i in run {
+                        output[placements[i].index] = None;
+                    }
+                    output[first.index] = Some(if first.reversed {
+                        (end, start)
+                    } else {
+                        (start, end)
+                    });
+                }
+
+                if k < line.len() {
+                    run_start = k;
+                    run_end = placements[line[k]].t1;
+                }
+            }
+        }
+    }
+
+    output.into_iter().flatten().collect()
+}
+
+/// Clip a polygon against a convex polygon (Sutherland–Hodgman)
+///
+/// Returns the part of `subject` inside `convex` as an open vertex list
+/// (empty when they don't overlap). `convex` may be wound either way. A
+/// concave subject that the clipper splits into several pieces comes back as
+/// one polygon joined along the clipper's edges.
+pub(crate) fn clip_polygon_to_convex(
+    subject: &[(f64, f64)],
+    convex: &[(f64, f64)],
+) -> Vec<(f64, f64)> {
+    let open = |poly: &[(f64, f64)]| {
+        if poly.len() > 1 && poly.first() == poly.last() {
+            poly[..poly.len() - 1].to_vec()
+        } else {
+            poly.to_vec()
+        }
+    };
+    let mut output = open(subject);
+    let clipper = open(convex);
+    if clipper.len() < 3 {
+        return Vec::new();
+    }
+
+    let orientation = signed_area(&clipper).signum();
+    let n = clipper.len();
+    for i in 0..n {
+        if output.is_empty() {
+            break;
+        }
+        let e0 = clipper[i];
+        let e1 = clipper[(i + 1) % n];
+        // Positive on the inner side of edge e0->e1
+        let side = |p: (f64, f64)| {
+            ((e1.0 - e0.0) * (p.1 - e0.1) - (e1.1 - e0.1) * (p.0 - e0.0)) * orientation
+        };
+
+        let input = std::mem::take(&mut output);
+        for k in 0..input.len() {
+            let p = input[k];
+            let q = input[(k + 1) % input.len()];
+            let (dp, dq) = (side(p), side(q));
+
+            if dp >= 0.0 {
+                output.push(p);
+            }
+            if (dp >= 0.0) != (dq >= 0.0) {
+                output.push(lerp(p, q, dp / (dp - dq)));
+            }
+        }
+    }
+
+    output
+}
+
+/// Whether (x, y) lies inside a polygon
+///
+/// Uses the even-odd rule, so holes formed by self-overlapping outlines
+/// count as outside. Polygons with fewer than 3 vertices contain nothing.
+#[pyfunction(name = "point_in_polygon")]
+pub fn py_point_in_polygon(x: f64, y: f64, polygon: Vec<(f64, f64)>) -> bool {
+    point_in_polygon(x, y, &polygon)
+}
+
+/// Signed area of a polygon (shoelace formula)
+///
+/// Positive for counter-clockwise vertices in a y-up frame, which appear
+/// clockwise on the y-down page; the absolute value is the area.
+#[pyfunction]
+pub fn polygon_area(polygon: Vec<(f64, f64)>) -> f64 {
+    signed_area(&polygon)
+}
+
+/// The polygon with its vertices in counter-clockwise (positive area) order
+///
+/// Reverses the vertex list when `polygon_area` is negative and returns it
+/// unchanged otherwise; a closed polygon stays closed.
+#[pyfunction]
+pub fn ensure_ccw(mut polygon: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    if signed_area(&polygon) < 0.0 {
+        polygon.reverse();
+    }
+    polygon
+}
@@ -3,9 +3,19 @@
 //! Fast geometric grid generation with distortions.
 //! Pure geometric calculations - blazing fast in Rust.
 
+use crate::error::AxiArtError;
+use crate::geometry::{clip_polyline_to_rect, Rect};
+use crate::paths::{Bounds, Generator, Paths};
+use crate::seed::resolve_seed;
+use noise::{NoiseFn, Perlin};
 use pyo3::prelude::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
 use std::f64::consts::PI;
 
+/// Graph nodes and the index pairs of the edges between them
+type Graph = (Vec<(f64, f64)>, Vec<(usize, usize)>);
+
 /// Grid types
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[pyclass(eq, eq_int)]
@@ -23,7 +33,23 @@ impl GridType {
             "square" => Ok(GridType::Square),
             "hexagonal" => Ok(GridType::Hexagonal),
             "triangular" => Ok(GridType::Triangular),
-            _ => Err(pyo3::exceptions::PyValueError::new_err("Invalid grid type")),
+            _ => Err(AxiArtError::InvalidOption("Invalid grid type".into()).into()),
+        }
+    }
+}
+
+/// Grid type argument accepted from Python: a `GridType` or its name
+#[derive(FromPyObject)]
+pub enum GridTypeArg {
+    Type(GridType),
+    Name(String),
+}
+
+impl GridTypeArg {
+    fn resolve(self) -> PyResult<GridType> {
+        match self {
+            GridTypeArg::Type(t) => Ok(t),
+            GridTypeArg::Name(name) => GridType::from_str(&name),
         }
     }
 }
@@ -35,28 +61,63 @@ impl GridType {
 pub struct GridGenerator {
     width: f64,
     height: f64,
+    /// Seed used by the last jittered grid, including one drawn from entropy
+    last_seed: Option<u64>,
 }
 
 #[pymethods]
 impl GridGenerator {
     #[new]
     fn new(width: f64, height: f64) -> Self {
-        GridGenerator { width, height }
+        GridGenerator {
+            width,
+            height,
+            last_seed: None,
+        }
+    }
+
+    /// Generate a grid of the given type
+    ///
+    /// Dispatches to the square, hexagonal or triangular generator.
+    /// `grid_type` accepts a `GridType` value or its name.
+    #[pyo3(signature = (grid_type=GridTypeArg::Type(GridType::Square), cell_size=10.0))]
+    fn generate(
+        &mut self,
+        grid_type: GridTypeArg,
+        cell_size: f64,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        match grid_type.resolve()? {
+            GridType::Square => self.generate_square_grid(cell_size, 0.0, None),
+            GridType::Hexagonal => self.generate_hexagonal_grid(cell_size, 0.0, None, 0.0, 50.0, 0),
+            GridType::Triangular => self.generate_triangular_grid(cell_size),
+        }
     }
 
     /// Generate square grid
-    #[pyo3(signature = (cell_size=10.0, jitter=0.0))]
-    fn generate_square_grid(&self, cell_size: f64, jitter: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+    ///
+    /// Jitter offsets are drawn from a `ChaCha8Rng`; pass `seed` to get the
+    /// same grid back on every call. A jittered grid keeps the seed in use as
+    /// `last_seed`; without jitter no seed is drawn.
+    #[pyo3(signature = (cell_size=10.0, jitter=0.0, seed=None))]
+    fn generate_square_grid(
+        &mut self,
+        cell_size: f64,
+        jitter: f64,
+        seed: Option<u64>,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
         let mut lines = Vec::new();
+        let mut rng = (jitter > 0.0).then(|| self.seeded_rng(seed));
 
         // Vertical lines
         let mut x = 0.0;
         while x <= self.width {
-            let x_offset = if jitter > 0.0 {
-                (rand::random::<f64>() - 0.5) * jitter
-            } else {
-                0.0
-            };
+            let x_offset = rng
+                .as_mut()
+                .map_or(0.0, |rng| (rng.gen::<f64>() - 0.5) * jitter);
             lines.push(vec![(x + x_offset, 0.0), (x + x_offset, self.height)]);
             x += cell_size;
         }
@@ -64,11 +125,9 @@ impl GridGenerator {
         // Horizontal lines
         let mut y = 0.0;
         while y <= self.height {
-            let y_offset = if jitter > 0.0 {
-                (rand::random::<f64>() - 0.5) * jitter
-            } else {
-                0.0
-            };
+            let y_offset = rng
+                .as_mut()
+                .map_or(0.0, |rng| (rng.gen::<f64>() - 0.5) * jitter);
             lines.push(vec![(0.0, y + y_offset), (self.width, y + y_offset)]);
             y += cell_size;
         }
@@ -77,10 +136,44 @@ impl GridGenerator {
     }
 
     /// Generate hexagonal grid
-    #[pyo3(signature = (cell_size=10.0))]
-    fn generate_hexagonal_grid(&self, cell_size: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+    ///
+    /// Each hexagon keeps its shape while its center is perturbed, so the
+    /// honeycomb looks hand-drawn rather than distorted:
+    /// - `jitter` moves each center independently along x and y by up to
+    ///   `jitter / 2`, with offsets drawn from a `ChaCha8Rng` as in
+    ///   `generate_square_grid`; pass `seed` for the same grid on every call
+    ///   (a jittered grid keeps the seed in use as `last_seed`)
+    /// - `warp_strength` adds a smooth offset of up to that amount from two
+    ///   seeded Perlin lookups (as in `apply_noise_distortion`), so
+    ///   neighboring cells drift together
+    ///
+    /// Centers never move further than those bounds, so keeping them small
+    /// relative to `cell_size` keeps cells from overlapping.
+    #[pyo3(signature = (
+        cell_size=10.0,
+        jitter=0.0,
+        seed=None,
+        warp_strength=0.0,
+        warp_scale=50.0,
+        warp_seed=0
+    ))]
+    fn generate_hexagonal_grid(
+        &mut self,
+        cell_size: f64,
+        jitter: f64,
+        seed: Option<u64>,
+        warp_strength: f64,
+        warp_scale: f64,
+        warp_seed: u32,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
         let mut lines = Vec::new();
         let h = cell_size * (3.0_f64.sqrt() / 2.0);
+        let mut rng = (jitter > 0.0).then(|| self.seeded_rng(seed));
+        let noise = Perlin::new(warp_seed);
 
         let mut row = 0;
         let mut y = 0.0;
@@ -89,8 +182,18 @@ impl GridGenerator {
             let mut x = x_offset;
 
             while x < self.width + cell_size {
+                let (mut cx, mut cy) = (x, y);
+                if let Some(rng) = rng.as_mut() {
+                    cx += (rng.gen::<f64>() - 0.5) * jitter;
+                    cy += (rng.gen::<f64>() - 0.5) * jitter;
+                }
+                if warp_strength != 0.0 {
+                    cx += noise.get([x / warp_scale, y / warp_scale]) * warp_strength;
+                    cy += noise.get([x / warp_scale + 31.7, y / warp_scale + 47.3]) * warp_strength;
+                }
+
                 // Draw hexagon
-                let hex_points = self.hexagon_points(x, y, cell_size / 2.0);
+                let hex_points = self.hexagon_points(cx, cy, cell_size / 2.0);
                 lines.push(hex_points);
                 x += cell_size;
             }
@@ -102,6 +205,204 @@ impl GridGenerator {
         Ok(lines)
     }
 
+    /// Generate square grid cells as closed polygons
+    ///
+    /// Each entry is one closed cell boundary (first point repeated at the
+    /// end). Cells on the right and bottom edges are trimmed to the canvas.
+    #[pyo3(signature = (cell_size=10.0))]
+    fn generate_square_cells(&self, cell_size: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
+        let mut cells = Vec::new();
+
+        let mut y = 0.0;
+        while y < self.height {
+            let y1 = (y + cell_size).min(self.height);
+            let mut x = 0.0;
+            while x < self.width {
+                let x1 = (x + cell_size).min(self.width);
+                cells.push(vec![(x, y), (x1, y), (x1, y1), (x, y1), (x, y)]);
+                x += cell_size;
+            }
+            y += cell_size;
+        }
+
+        Ok(cells)
+    }
+
+    /// Generate the square grid as a graph of intersections
+    ///
+    /// Returns (nodes, edges): a node at every crossing of the lines drawn by
+    /// `generate_square_grid` (without jitter), in row-major order, and an
+    /// edge as a pair of node indices for every cell side between two
+    /// neighboring crossings. Suited to graph algorithms such as spanning
+    /// trees or random walks for carving mazes and circuit-board traces.
+    #[pyo3(signature = (cell_size=10.0))]
+    fn generate_square_graph(&self, cell_size: f64) -> PyResult<Graph> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
+        // Same line positions as generate_square_grid
+        let positions = |extent: f64| {
+            let mut coords = Vec::new();
+            let mut c = 0.0;
+            while c <= extent {
+                coords.push(c);
+                c += cell_size;
+            }
+            coords
+        };
+        let xs = positions(self.width);
+        let ys = positions(self.height);
+        let cols = xs.len();
+
+        let mut nodes = Vec::with_capacity(cols * ys.len());
+        let mut edges = Vec::new();
+        for (row, &y) in ys.iter().enumerate() {
+            for (col, &x) in xs.iter().enumerate() {
+                let idx = nodes.len();
+                nodes.push((x, y));
+                if col > 0 {
+                    edges.push((idx - 1, idx));
+                }
+                if row > 0 {
+                    edges.push((idx - cols, idx));
+                }
+            }
+        }
+
+        Ok((nodes, edges))
+    }
+
+    /// Generate hexagonal grid cells as closed polygons
+    ///
+    /// Pointy-top hexagons that tile the plane without gaps or overlaps:
+    /// `cell_size` is the flat-to-flat width, rows are `cell_size * sqrt(3) / 2`
+    /// apart and odd rows are offset by half a cell. Cells along the edges
+    /// extend past the canvas so it is fully covered.
+    #[pyo3(signature = (cell_size=10.0))]
+    fn generate_hexagonal_cells(&self, cell_size: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
+        let mut cells = Vec::new();
+        let radius = cell_size / 3.0_f64.sqrt();
+        let h = cell_size * (3.0_f64.sqrt() / 2.0);
+
+        let mut row = 0;
+        let mut y = 0.0;
+        while y < self.height + radius {
+            let x_offset = if row % 2 == 0 { 0.0 } else { cell_size / 2.0 };
+            let mut x = x_offset;
+
+            while x < self.width + cell_size / 2.0 {
+                cells.push(self.hexagon_points_rotated(x, y, radius, PI / 6.0));
+                x += cell_size;
+            }
+
+            y += h;
+            row += 1;
+        }
+
+        Ok(cells)
+    }
+
+    /// Generate triangular grid
+    ///
+    /// Three families of parallel lines at 0°, 60° and 120°, spaced so they
+    /// meet at the vertices of equilateral triangles with side `cell_size`.
+    /// Every line is clipped to the canvas, so the tiling has no gaps at the
+    /// edges.
+    #[pyo3(signature = (cell_size=10.0))]
+    fn generate_triangular_grid(&self, cell_size: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+
+        let mut lines = Vec::new();
+        let sqrt3 = 3.0_f64.sqrt();
+        let h = cell_size * sqrt3 / 2.0;
+
+        // Horizontal lines (0°)
+        let mut y = 0.0;
+        while y <= self.height {
+            lines.push(vec![(0.0, y), (self.width, y)]);
+            y += h;
+        }
+
+        // Sloped lines x = c ± y/√3, with c on multiples of cell_size so all
+        // three families share the same lattice vertices
+        let span = self.height / sqrt3;
+        let first = (-span / cell_size).floor() as i64;
+        let last = ((self.width + span) / cell_size).ceil() as i64;
+        for k in first..=last {
+            let c = k as f64 * cell_size;
+            for slope in [1.0, -1.0] {
+                if let Some(line) = self.clip_sloped_line(c, slope / sqrt3) {
+                    lines.push(line);
+                }
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Generate polar (radial) grid
+    ///
+    /// Concentric circles evenly spaced out to the max radius, plus spokes
+    /// from the center to the outermost ring. Like `SpiralGenerator`, the
+    /// center defaults to the canvas center and the max radius to 90% of the
+    /// distance from the center to the nearest edge.
+    #[pyo3(signature = (
+        num_rings=10,
+        num_spokes=12,
+        center=None,
+        max_radius=None,
+        points_per_ring=100
+    ))]
+    fn generate_radial_grid(
+        &self,
+        num_rings: usize,
+        num_spokes: usize,
+        center: Option<(f64, f64)>,
+        max_radius: Option<f64>,
+        points_per_ring: usize,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let (cx, cy) = center.unwrap_or((self.width / 2.0, self.height / 2.0));
+        let max_radius = max_radius
+            .unwrap_or_else(|| cx.min(self.width - cx).min(cy).min(self.height - cy) * 0.9);
+        let points_per_ring = points_per_ring.max(3);
+
+        let mut lines = Vec::with_capacity(num_rings + num_spokes);
+
+        // Concentric rings
+        for ring in 1..=num_rings {
+            let r = max_radius * ring as f64 / num_rings as f64;
+            let ring_points = (0..=points_per_ring)
+                .map(|i| {
+                    let theta = (i as f64 / points_per_ring as f64) * 2.0 * PI;
+                    (cx + r * theta.cos(), cy + r * theta.sin())
+                })
+                .collect();
+            lines.push(ring_points);
+        }
+
+        // Radial spokes
+        for spoke in 0..num_spokes {
+            let theta = (spoke as f64 / num_spokes as f64) * 2.0 * PI;
+            lines.push(vec![
+                (cx, cy),
+                (cx + max_radius * theta.cos(), cy + max_radius * theta.sin()),
+            ]);
+        }
+
+        Ok(lines)
+    }
+
     /// Apply radial distortion to grid
     #[pyo3(signature = (lines, center=None, strength=0.5))]
     fn apply_radial_distortion(
@@ -129,15 +430,150 @@ impl GridGenerator {
             })
             .collect())
     }
+
+    /// Apply Perlin noise distortion to grid
+    ///
+    /// Offsets every vertex by a vector of two seeded Perlin lookups (sampled
+    /// at decorrelated positions), scaled by `strength`. Accepts and returns
+    /// the same line format as the other distortions so they can be chained.
+    #[pyo3(signature = (lines, scale=50.0, strength=5.0, seed=0))]
+    fn apply_noise_distortion(
+        &self,
+        lines: Vec<Vec<(f64, f64)>>,
+        scale: f64,
+        strength: f64,
+        seed: u32,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let noise = Perlin::new(seed);
+
+        Ok(lines
+            .into_iter()
+            .map(|line| {
+                line.into_iter()
+                    .map(|(x, y)| {
+                        let nx = noise.get([x / scale, y / scale]);
+                        let ny = noise.get([x / scale + 31.7, y / scale + 47.3]);
+
+                        (x + nx * strength, y + ny * strength)
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Clip lines to the canvas bounds
+    ///
+    /// Cohen–Sutherland clipping of every segment against
+    /// [0, width] x [0, height]. Polylines that leave and re-enter the canvas
+    /// are split into separate pieces. Works on any generator's polylines,
+    /// typically as the last step after a distortion.
+    fn clip_to_bounds(&self, lines: Vec<Vec<(f64, f64)>>) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let rect = Rect::new(0.0, 0.0, self.width, self.height);
+
+        Ok(lines
+            .iter()
+            .flat_map(|line| clip_polyline_to_rect(line, &rect))
+            .collect())
+    }
+
+    /// Apply twist (swirl) distortion to grid
+    ///
+    /// Rotates each vertex about `center` by `strength * distance` radians,
+    /// producing a vortex warp. Same input/output as `apply_radial_distortion`
+    /// so distortions can be chained.
+    #[pyo3(signature = (lines, center=None, strength=0.01))]
+    fn apply_twist_distortion(
+        &self,
+        lines: Vec<Vec<(f64, f64)>>,
+        center: Option<(f64, f64)>,
+        strength: f64,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let cx = center.map(|c| c.0).unwrap_or(self.width / 2.0);
+        let cy = center.map(|c| c.1).unwrap_or(self.height / 2.0);
+
+        Ok(lines
+            .into_iter()
+            .map(|line| {
+                line.into_iter()
+                    .map(|(x, y)| {
+                        let dx = x - cx;
+                        let dy = y - cy;
+                        let dist = (dx * dx + dy * dy).sqrt();
+                        let (sin, cos) = (strength * dist).sin_cos();
+
+                        (cx + dx * cos - dy * sin, cy + dx * sin + dy * cos)
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Get the seed used by the last jittered square or hexagonal grid
+    ///
+    /// Includes one drawn from entropy, so pass it back as `seed` to
+    /// reproduce a jitter you liked. None until one of them has run.
+    #[getter]
+    fn last_seed(&self) -> Option<u64> {
+        self.last_seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl GridGenerator {
+    /// RNG for jitter offsets, recording its seed in `last_seed`
+    ///
+    /// Only called for jittered grids, so plain ones never take a seed from
+    /// the global stream and shift the seeds of later generators.
+    fn seeded_rng(&mut self, seed: Option<u64>) -> ChaCha8Rng {
+        // Draw a seed from entropy when none is given, so it can be reported
+        let seed = resolve_seed(seed).unwrap_or_else(|| rand::thread_rng().gen());
+        self.last_seed = Some(seed);
+        ChaCha8Rng::seed_from_u64(seed)
+    }
+
+    /// Clip the line x = c + slope * y to the canvas rectangle
+    fn clip_sloped_line(&self, c: f64, slope: f64) -> Option<Vec<(f64, f64)>> {
+        // y range where 0 <= x <= width
+        let (y_a, y_b) = ((0.0 - c) / slope, (self.width - c) / slope);
+        let y0 = y_a.min(y_b).max(0.0);
+        let y1 = y_a.max(y_b).min(self.height);
+
+        if y1 - y0 <= 1e-9 {
+            return None;
+        }
+
+        Some(vec![(c + slope * y0, y0), (c + slope * y1, y1)])
+    }
+
     /// Generate hexagon vertices
     fn hexagon_points(&self, cx: f64, cy: f64, radius: f64) -> Vec<(f64, f64)> {
+        self.hexagon_points_rotated(cx, cy, radius, 0.0)
+    }
+
+    /// Generate hexagon vertices with the first vertex at `rotation` radians
+    fn hexagon_points_rotated(
+        &self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        rotation: f64,
+    ) -> Vec<(f64, f64)> {
         let mut points = Vec::with_capacity(7);
 
         for i in 0..=6 {
-            let angle = PI / 3.0 * i as f64;
+            let angle = rotation + PI / 3.0 * i as f64;
             let x = cx + radius * angle.cos();
             let y = cy + radius * angle.sin();
             points.push((x, y));
@@ -146,3 +582,15 @@ impl GridGenerator {
         points
     }
 }
+
+impl Generator for GridGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        self.generate(GridTypeArg::Type(GridType::Square), 10.0)
+            .map(Paths)
+    }
+
+    /// The default square grid runs edge to edge across the canvas
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Ok(Some((0.0, 0.0, self.width, self.height)))
+    }
+}
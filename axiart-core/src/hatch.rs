@@ -0,0 +1,110 @@
+//! Hatching fills for closed shapes
+//!
+//! Shades polygons with evenly spaced parallel lines using a scanline fill:
+//! the polygon is rotated so the hatch direction is horizontal, each scanline
+//! is intersected with the polygon's edges, and the spans between pairs of
+//! crossings (even-odd rule) are rotated back as hatch segments.
+
+use crate::error::AxiArtError;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+
+/// Fill a polygon with parallel hatch lines
+///
+/// # Arguments
+/// * `polygon` - Vertex list, open or closed (e.g. a Voronoi or grid cell)
+/// * `angle` - Hatch direction in degrees (0 = horizontal)
+/// * `spacing` - Distance between neighboring hatch lines
+/// * `cross_angle` - Optional second direction in degrees for cross-hatching
+///
+/// # Returns
+/// Hatch segments as 2-point polylines. Successive lines alternate direction
+/// so the pen zig-zags across the shape instead of returning to one side.
+#[pyfunction]
+#[pyo3(signature = (polygon, angle=45.0, spacing=2.0, cross_angle=None))]
+pub fn hatch_polygon(
+    polygon: Vec<(f64, f64)>,
+    angle: f64,
+    spacing: f64,
+    cross_angle: Option<f64>,
+) -> PyResult<Paths> {
+    if spacing <= 0.0 {
+        return Err(AxiArtError::InvalidParameter("spacing must be positive".into()).into());
+    }
+
+    let mut lines = hatch_lines(&polygon, angle.to_radians(), spacing);
+    if let Some(cross) = cross_angle {
+        lines.extend(hatch_lines(&polygon, cross.to_radians(), spacing));
+    }
+
+    Ok(Paths(lines))
+}
+
+/// Scanline hatch of one polygon at `angle` radians
+fn hatch_lines(polygon: &[(f64, f64)], angle: f64, spacing: f64) -> Vec<Vec<(f64, f64)>> {
+    let mut vertices = polygon.to_vec();
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+
+    // Rotate by -angle so hatch lines run along x
+    let (sin, cos) = angle.sin_cos();
+    let rotated: Vec<(f64, f64)> = vertices
+        .iter()
+        .map(|&(x, y)| (x * cos + y * sin, -x * sin + y * cos))
+        .collect();
+    let unrotate = |(u, v): (f64, f64)| (u * cos - v * sin, u * sin + v * cos);
+
+    let (min_v, max_v) = rotated
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &(_, v)| {
+            (lo.min(v), hi.max(v))
+        });
+
+    // Center the scanlines in the shape's extent, keeping them off the
+    // outermost vertices
+    let extent = max_v - min_v;
+    let count = (extent / spacing).ceil() as usize;
+    let offset = min_v + (extent - count.saturating_sub(1) as f64 * spacing) / 2.0;
+
+    let mut lines = Vec::new();
+    let mut crossings = Vec::new();
+    for k in 0..count {
+        let v = offset + k as f64 * spacing;
+
+        crossings.clear();
+        let n = rotated.len();
+        for i in 0..n {
+            let (u1, v1) = rotated[i];
+            let (u2, v2) = rotated[(i + 1) % n];
+            // Half-open rule so a scanline through a vertex counts it once
+            if (v1 <= v) != (v2 <= v) {
+                crossings.push(u1 + (v - v1) / (v2 - v1) * (u2 - u1));
+            }
+        }
+        crossings.sort_by(f64::total_cmp);
+
+        let reverse = k % 2 == 1;
+        let mut spans: Vec<Vec<(f64, f64)>> = crossings
+            .chunks_exact(2)
+            .filter(|pair| pair[1] > pair[0])
+            .map(|pair| {
+                let (a, b) = (unrotate((pair[0], v)), unrotate((pair[1], v)));
+                if reverse {
+                    vec![b, a]
+                } else {
+                    vec![a, b]
+                }
+            })
+            .collect();
+        if reverse {
+            spans.reverse();
+        }
+        lines.extend(spans);
+    }
+
+    lines
+}
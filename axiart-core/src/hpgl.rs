@@ -0,0 +1,98 @@
+//! HPGL export for vintage pen plotters
+//!
+//! Emits the HP-GL subset understood by HP and compatible plotters such as
+//! the 7475A: `IN` to initialize, `SP` to select a pen, `PU` / `PD` to raise
+//! and lower it, and `PA` absolute moves in integer plotter units.
+
+use crate::error::AxiArtError;
+use crate::gcode::flip_height;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+use std::fmt::Write;
+
+/// Render polylines as an HPGL program
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points, in drawing order
+/// * `scale` - Plotter units per canvas unit; the default 40 maps
+///   millimeters to the standard 0.025 mm plotter unit
+/// * `pens` - Optional pen number per path (defaults to pen 1 throughout).
+///   A `SP` pen change is emitted whenever the pen differs from the previous
+///   path's, so concatenating layers with one pen number each plots every
+///   layer with its own pen
+/// * `y_flip` - Mirror Y for the plotter's bottom-left origin (the canvas
+///   origin is top-left), mapping y to `height - y`
+/// * `height` - Canvas height used by `y_flip`; required when flipping
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import to_hpgl
+///
+/// paths = black_paths + red_paths
+/// pens = [1] * len(black_paths) + [2] * len(red_paths)
+/// program = to_hpgl(paths, pens=pens, y_flip=True, height=210.0)
+/// serial_port.write(program.encode("ascii"))
+/// ```
+#[pyfunction]
+#[pyo3(signature = (paths, scale=40.0, pens=None, y_flip=false, height=None))]
+pub fn to_hpgl(
+    paths: Paths,
+    scale: f64,
+    pens: Option<Vec<u32>>,
+    y_flip: bool,
+    height: Option<f64>,
+) -> PyResult<String> {
+    if scale <= 0.0 {
+        return Err(AxiArtError::InvalidParameter("scale must be positive".into()).into());
+    }
+    if pens
+        .as_ref()
+        .is_some_and(|pens| pens.len() != paths.0.len())
+    {
+        return Err(
+            AxiArtError::InvalidParameter("pens must have one entry per path".into()).into(),
+        );
+    }
+
+    let flip_height = flip_height(y_flip, height)?;
+
+    let units = |(x, y): (f64, f64)| {
+        let y = flip_height.map_or(y, |height| height - y);
+        ((x * scale).round() as i64, (y * scale).round() as i64)
+    };
+
+    let mut current_pen = pens
+        .as_ref()
+        .and_then(|pens| pens.first())
+        .map_or(1, |&pen| pen);
+    let mut hpgl = String::new();
+    // Writing to a String cannot fail
+    let _ = writeln!(hpgl, "IN;");
+    let _ = writeln!(hpgl, "SP{current_pen};");
+
+    for (i, path) in paths.0.iter().enumerate() {
+        if path.is_empty() {
+            continue;
+        }
+        let _ = writeln!(hpgl, "PU;");
+        let pen = pens.as_ref().map_or(1, |pens| pens[i]);
+        if pen != current_pen {
+            let _ = writeln!(hpgl, "SP{pen};");
+            current_pen = pen;
+        }
+
+        let (x, y) = units(path[0]);
+        let _ = writeln!(hpgl, "PA{x},{y};");
+        let _ = writeln!(hpgl, "PD;");
+        for &point in &path[1..] {
+            let (x, y) = units(point);
+            let _ = writeln!(hpgl, "PA{x},{y};");
+        }
+    }
+
+    // Raise the pen and put it back in the carousel
+    let _ = writeln!(hpgl, "PU;");
+    let _ = writeln!(hpgl, "SP0;");
+    Ok(hpgl)
+}
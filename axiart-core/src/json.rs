@@ -0,0 +1,142 @@
+//! JSON serialization of path sets
+//!
+//! A stable on-disk format for generator output, so a pattern can be
+//! generated once and replayed or post-processed later (or in another
+//! language) without rerunning the algorithm. A document looks like
+//!
+//! ```json
+//! {"version": 1, "paths": [{"points": [[0.0, 0.0], [10.0, 5.0]], "color": "red", "layer": "1"}]}
+//! ```
+//!
+//! where `color` and `layer` are optional per-path metadata and are left out
+//! when not set.
+
+use crate::error::AxiArtError;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Format version written by `paths_to_json`; readers reject newer versions
+const FORMAT_VERSION: u32 = 1;
+
+/// Polylines read back with their per-path (color, layer) metadata
+type PathsWithMetadata = (Paths, Vec<Option<String>>, Vec<Option<String>>);
+
+#[derive(Serialize, Deserialize)]
+struct Document {
+    version: u32,
+    paths: Vec<PathRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PathRecord {
+    points: Vec<(f64, f64)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layer: Option<String>,
+}
+
+/// Serialize polylines to a versioned JSON document
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `colors` - Optional color per path (None entries are left out)
+/// * `layers` - Optional layer name per path (None entries are left out)
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import FlowFieldGenerator, paths_to_json, paths_from_json
+///
+/// flow = FlowFieldGenerator(297.0, 210.0, seed=42)
+/// open("flow.json", "w").write(paths_to_json(flow.paths()))
+/// paths = paths_from_json(open("flow.json").read())
+/// ```
+#[pyfunction]
+#[pyo3(signature = (paths, colors=None, layers=None))]
+pub fn paths_to_json(
+    paths: Paths,
+    colors: Option<Vec<Option<String>>>,
+    layers: Option<Vec<Option<String>>>,
+) -> PyResult<String> {
+    for (name, values) in [("colors", &colors), ("layers", &layers)] {
+        if values.as_ref().is_some_and(|v| v.len() != paths.0.len()) {
+            return Err(AxiArtError::InvalidParameter(format!(
+                "{} must have one entry per path",
+                name
+            ))
+            .into());
+        }
+    }
+    // JSON has no NaN or infinity; serde_json would write them as null
+    if paths
+        .0
+        .iter()
+        .flatten()
+        .any(|&(x, y)| !x.is_finite() || !y.is_finite())
+    {
+        return Err(AxiArtError::InvalidParameter("path coordinates must be finite".into()).into());
+    }
+
+    let mut colors = colors.into_iter().flatten();
+    let mut layers = layers.into_iter().flatten();
+    let document = Document {
+        version: FORMAT_VERSION,
+        paths: paths
+            .0
+            .into_iter()
+            .map(|points| PathRecord {
+                points,
+                color: colors.next().flatten(),
+                layer: layers.next().flatten(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&document)
+        .map_err(|e| AxiArtError::InvalidParameter(e.to_string()).into())
+}
+
+/// Read polylines back from a `paths_to_json` document
+///
+/// # Arguments
+/// * `s` - JSON document
+/// * `return_metadata` - Also return the per-path colors and layers
+///
+/// # Returns
+/// The polylines, or (paths, colors, layers) with `return_metadata`, where
+/// colors and layers have one entry per path (None where unset)
+#[pyfunction]
+#[pyo3(signature = (s, return_metadata=false))]
+pub fn paths_from_json(py: Python<'_>, s: &str, return_metadata: bool) -> PyResult<PyObject> {
+    let (paths, colors, layers) = parse(s)?;
+
+    if return_metadata {
+        Ok((paths, colors, layers).into_py(py))
+    } else {
+        Ok(paths.into_py(py))
+    }
+}
+
+/// Parse and validate a document
+fn parse(s: &str) -> Result<PathsWithMetadata, AxiArtError> {
+    let document: Document = serde_json::from_str(s)
+        .map_err(|e| AxiArtError::InvalidParameter(format!("invalid paths JSON: {}", e)))?;
+    if document.version > FORMAT_VERSION {
+        return Err(AxiArtError::InvalidParameter(format!(
+            "paths JSON version {} is newer than the supported version {}",
+            document.version, FORMAT_VERSION
+        )));
+    }
+
+    let mut paths = Vec::with_capacity(document.paths.len());
+    let mut colors = Vec::with_capacity(document.paths.len());
+    let mut layers = Vec::with_capacity(document.paths.len());
+    for record in document.paths {
+        paths.push(record.points);
+        colors.push(record.color);
+        layers.push(record.layer);
+    }
+    Ok((Paths(paths), colors, layers))
+}
@@ -0,0 +1,187 @@
+//! 2D KD-tree for nearest neighbor queries
+//!
+//! A static, balanced tree stored as a permutation of point indices: the
+//! median of each index range is the node, with the lower and upper halves as
+//! its subtrees. Queries take the distance as a closure so callers can use any
+//! metric whose value along a single axis never exceeds the full distance
+//! (true for Euclidean, Manhattan, Chebyshev and Minkowski).
+
+/// Balanced 2D KD-tree over a fixed point set
+pub(crate) struct KdTree<'a> {
+    points: &'a [(f64, f64)],
+    order: Vec<usize>,
+}
+
+impl<'a> KdTree<'a> {
+    /// Build a tree over `points` in O(n log n)
+    pub(crate) fn new(points: &'a [(f64, f64)]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut order, 0);
+        KdTree { points, order }
+    }
+
+    fn build(points: &[(f64, f64)], order: &mut [usize], depth: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |&a, &b| {
+            Self::coord(points[a], depth).total_cmp(&Self::coord(points[b], depth))
+        });
+
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    #[inline]
+    fn coord(p: (f64, f64), depth: usize) -> f64 {
+        if depth.is_multiple_of(2) {
+            p.0
+        } else {
+            p.1
+        }
+    }
+
+    /// Index of the point nearest to (x, y)
+    ///
+    /// `distance(dx, dy)` may be any monotonic stand-in for a distance (e.g.
+    /// squared Euclidean). Ties go to the lowest index, matching a linear scan
+    /// with `Iterator::min_by`.
+    pub(crate) fn nearest<F>(&self, x: f64, y: f64, distance: F) -> Option<usize>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let mut best: Option<(f64, usize)> = None;
+        self.search(&self.order, 0, (x, y), &distance, &mut best);
+        best.map(|(_, idx)| idx)
+    }
+
+    fn search<F>(
+        &self,
+        order: &[usize],
+        depth: usize,
+        query: (f64, f64),
+        distance: &F,
+        best: &mut Option<(f64, usize)>,
+    ) where
+        F: Fn(f64, f64) -> f64,
+    {
+        if order.is_empty() {
+            return;
+        }
+
+        let mid = order.len() / 2;
+        let idx = order[mid];
+        let p = self.points[idx];
+
+        let d = distance(query.0 - p.0, query.1 - p.1);
+        let better = match *best {
+            None => true,
+            Some((best_d, best_idx)) => d < best_d || (d == best_d && idx < best_idx),
+        };
+        if better {
+            *best = Some((d, idx));
+        }
+
+        let diff = Self::coord(query, depth) - Self::coord(p, depth);
+        let (near, far) = if diff < 0.0 {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+
+        self.search(near, depth + 1, query, distance, best);
+
+        // Visit the far side unless the splitting plane is already farther
+        // than the best match (ties must be visited for index ordering)
+        let plane = if depth.is_multiple_of(2) {
+            distance(diff, 0.0)
+        } else {
+            distance(0.0, diff)
+        };
+        if best.is_none_or(|(best_d, _)| plane <= best_d) {
+            self.search(far, depth + 1, query, distance, best);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distance::Metric;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    const METRICS: [Metric; 4] = [
+        Metric::Euclidean,
+        Metric::Manhattan,
+        Metric::Chebyshev,
+        Metric::Minkowski(3.0),
+    ];
+
+    /// The linear scan the tree replaced: the first minimum wins
+    fn linear_nearest(points: &[(f64, f64)], x: f64, y: f64, metric: Metric) -> Option<usize> {
+        let key = |&(px, py): &(f64, f64)| metric.compare_key(x - px, y - py);
+        points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| key(a).total_cmp(&key(b)))
+            .map(|(idx, _)| idx)
+    }
+
+    fn assert_matches_linear_scan(points: &[(f64, f64)], queries: &[(f64, f64)]) {
+        let tree = KdTree::new(points);
+        for metric in METRICS {
+            for &(x, y) in queries {
+                assert_eq!(
+                    tree.nearest(x, y, |dx, dy| metric.compare_key(dx, dy)),
+                    linear_nearest(points, x, y, metric),
+                    "{:?} query ({}, {})",
+                    metric,
+                    x,
+                    y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_matches_linear_scan_on_random_sites() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for n in [1, 2, 5, 100] {
+            let points: Vec<(f64, f64)> = (0..n)
+                .map(|_| (rng.gen::<f64>() * 100.0, rng.gen::<f64>() * 100.0))
+                .collect();
+            let queries: Vec<(f64, f64)> = (0..500)
+                .map(|_| {
+                    (
+                        rng.gen::<f64>() * 140.0 - 20.0,
+                        rng.gen::<f64>() * 140.0 - 20.0,
+                    )
+                })
+                .collect();
+            assert_matches_linear_scan(&points, &queries);
+        }
+    }
+
+    #[test]
+    fn ties_go_to_the_lowest_index() {
+        // Integer sites with repeats, queried on the half-integer lattice so
+        // many queries are equidistant from several sites
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        let mut points: Vec<(f64, f64)> = (0..60)
+            .map(|_| (rng.gen_range(0..8) as f64, rng.gen_range(0..8) as f64))
+            .collect();
+        points.extend_from_within(..20);
+        let queries: Vec<(f64, f64)> = (-2..=18)
+            .flat_map(|i| (-2..=18).map(move |j| (i as f64 * 0.5, j as f64 * 0.5)))
+            .collect();
+        assert_matches_linear_scan(&points, &queries);
+
+        // Every site in one place: the first copy wins
+        let tree = KdTree::new(&[(3.0, 3.0); 9]);
+        assert_eq!(tree.nearest(0.0, 0.0, |dx, dy| dx * dx + dy * dy), Some(0));
+    }
+}
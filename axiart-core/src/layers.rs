@@ -0,0 +1,131 @@
+//! Layer assignment for multi-pen plots
+//!
+//! Partitions a path set into named layers, returned as a Python dict mapping
+//! each label to its paths, ready to draw onto separate SVG layers (one per
+//! pen), or into weight classes for value-annotated output.
+
+use crate::error::AxiArtError;
+use crate::geometry::polyline_length;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// Split paths into layers by drawn length
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `thresholds` - Ascending length boundaries; n thresholds give n + 1
+///   layers, with a path of length l in the first layer whose upper boundary
+///   exceeds l
+/// * `labels` - Names for the n + 1 layers (defaults to "layer_0",
+///   "layer_1", ...)
+///
+/// # Returns
+/// Dict of label -> paths with every label present, in threshold order
+#[pyfunction]
+#[pyo3(signature = (paths, thresholds, labels=None))]
+pub fn group_by_length(
+    py: Python<'_>,
+    paths: Paths,
+    thresholds: Vec<f64>,
+    labels: Option<Vec<String>>,
+) -> PyResult<Bound<'_, PyDict>> {
+    if thresholds.windows(2).any(|w| w[1] < w[0]) {
+        return Err(
+            AxiArtError::InvalidParameter("thresholds must be in ascending order".into()).into(),
+        );
+    }
+    let labels = match labels {
+        Some(labels) if labels.len() != thresholds.len() + 1 => {
+            return Err(AxiArtError::InvalidParameter(
+                "labels must have one more entry than thresholds".into(),
+            )
+            .into())
+        }
+        Some(labels) => labels,
+        None => (0..=thresholds.len())
+            .map(|i| format!("layer_{}", i))
+            .collect(),
+    };
+
+    let mut groups = vec![Vec::new(); labels.len()];
+    for path in paths.0 {
+        let length = polyline_length(&path);
+        groups[thresholds.partition_point(|&t| t <= length)].push(path);
+    }
+
+    let layers = PyDict::new_bound(py);
+    for (label, group) in labels.into_iter().zip(groups) {
+        layers.set_item(label, group)?;
+    }
+    Ok(layers)
+}
+
+/// Split paths into layers with a custom classifier
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `classifier` - Callable taking one path (a list of (x, y) tuples) and
+///   returning its layer label (any hashable, e.g. a pen name), so layers can
+///   be chosen by length, position, bounding region or anything else
+///
+/// # Returns
+/// Dict of label -> paths, with labels in the order they were first returned
+#[pyfunction]
+pub fn assign_layers<'py>(
+    py: Python<'py>,
+    paths: Paths,
+    classifier: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let layers = PyDict::new_bound(py);
+    for path in paths.0 {
+        let path = path.into_py(py);
+        let label = classifier.call1((&path,))?;
+        match layers.get_item(&label)? {
+            Some(group) => group.downcast::<PyList>()?.append(path)?,
+            None => layers.set_item(label, PyList::new_bound(py, [path]))?,
+        }
+    }
+    Ok(layers)
+}
+
+/// Bucket value-annotated paths into line-weight classes
+///
+/// Each path carries one value, such as its mean field magnitude or noise
+/// intensity. The value range is split into `levels` equal-width classes and
+/// every path goes to the class its value falls in, so each class can get its
+/// own pen or number of overdraw passes.
+///
+/// # Arguments
+/// * `paths_with_mag` - (path, value) pairs
+/// * `levels` - Number of weight classes
+///
+/// # Returns
+/// `levels` path groups from the lowest values to the highest. When all
+/// values are equal every path lands in the first group.
+#[pyfunction]
+pub fn magnitude_to_passes(
+    paths_with_mag: Vec<(Vec<(f64, f64)>, f64)>,
+    levels: usize,
+) -> PyResult<Vec<Paths>> {
+    if levels == 0 {
+        return Err(AxiArtError::InvalidParameter("levels must be positive".into()).into());
+    }
+
+    let (lo, hi) = paths_with_mag
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &(_, v)| {
+            (lo.min(v), hi.max(v))
+        });
+
+    let mut groups = vec![Paths::default(); levels];
+    for (path, value) in paths_with_mag {
+        let class = if hi > lo {
+            (((value - lo) / (hi - lo) * levels as f64) as usize).min(levels - 1)
+        } else {
+            0
+        };
+        groups[class].0.push(path);
+    }
+    Ok(groups)
+}
@@ -13,19 +13,42 @@
 
 use pyo3::prelude::*;
 
+mod cancel;
+mod clip;
+mod coverage;
+mod delaunay;
 mod dendrite;
+mod distance;
+mod error;
 mod flow_field;
+mod gcode;
+mod geometry;
 mod grid;
+mod hatch;
+mod hpgl;
+mod json;
+mod kdtree;
+mod layers;
 mod lsystem;
 mod noise_core;
 mod noise_pattern;
+mod offset;
+mod optimize;
+mod paths;
+mod seed;
+mod smooth;
+mod spatial_hash;
 mod spiral;
+mod svg;
+mod threads;
+mod transform;
 mod truchet;
 mod voronoi;
 
 /// AxiArt Core - Python module for high-performance pattern generation
 #[pymodule]
 fn axiart_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    error::register(m)?;
     m.add_class::<dendrite::DendriteGenerator>()?;
     m.add_class::<dendrite::BranchingStyle>()?;
     m.add_class::<noise_core::PerlinNoise>()?;
@@ -33,12 +56,49 @@ fn axiart_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<flow_field::FieldType>()?;
     m.add_class::<noise_pattern::NoisePatternGenerator>()?;
     m.add_class::<spiral::SpiralGenerator>()?;
+    m.add_class::<spiral::SpiralType>()?;
     m.add_class::<grid::GridGenerator>()?;
+    m.add_class::<grid::GridType>()?;
     m.add_class::<voronoi::VoronoiGenerator>()?;
     m.add_class::<lsystem::LSystemGenerator>()?;
     m.add_class::<lsystem::LSystemPreset>()?;
     m.add_class::<truchet::TruchetGenerator>()?;
     m.add_class::<truchet::TileType>()?;
+    m.add_class::<spatial_hash::SpatialHash>()?;
+    m.add_function(wrap_pyfunction!(seed::set_global_seed, m)?)?;
+    m.add_function(wrap_pyfunction!(threads::set_num_threads, m)?)?;
+    m.add_function(wrap_pyfunction!(svg::to_svg, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::reorder_paths, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::segments_to_polylines, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::to_segments, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::dedup_segments, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::weld_points, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::pen_up_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(optimize::pen_down_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(coverage::ink_coverage, m)?)?;
+    m.add_function(wrap_pyfunction!(hatch::hatch_polygon, m)?)?;
+    m.add_function(wrap_pyfunction!(offset::offset_polygon, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::py_point_in_polygon, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::polygon_area, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::ensure_ccw, m)?)?;
+    m.add_function(wrap_pyfunction!(clip::clip_to_rect, m)?)?;
+    m.add_function(wrap_pyfunction!(clip::occlude, m)?)?;
+    m.add_function(wrap_pyfunction!(layers::group_by_length, m)?)?;
+    m.add_function(wrap_pyfunction!(layers::assign_layers, m)?)?;
+    m.add_function(wrap_pyfunction!(layers::magnitude_to_passes, m)?)?;
+    m.add_function(wrap_pyfunction!(smooth::smooth_paths, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::translate, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::rotate, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::scale, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::mirror, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::radial_symmetry, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::apply_matrix, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::bounding_box, m)?)?;
+    m.add_function(wrap_pyfunction!(transform::fit_to_page, m)?)?;
+    m.add_function(wrap_pyfunction!(gcode::to_gcode, m)?)?;
+    m.add_function(wrap_pyfunction!(hpgl::to_hpgl, m)?)?;
+    m.add_function(wrap_pyfunction!(json::paths_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(json::paths_from_json, m)?)?;
 
     Ok(())
 }
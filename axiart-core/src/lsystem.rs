@@ -3,6 +3,9 @@
 //! Generates fractal patterns and organic structures using string rewriting systems.
 //! Supports turtle graphics interpretation for creating complex branching structures.
 
+use crate::error::AxiArtError;
+use crate::geometry::remove_collinear_overlaps;
+use crate::paths::{Bounds, Generator, Paths};
 use pyo3::prelude::*;
 use std::collections::HashMap;
 use std::f64::consts::PI;
@@ -13,8 +16,20 @@ struct TurtleState {
     x: f64,
     y: f64,
     angle: f64,
+    /// Pen color index, advanced by `'`
+    color: usize,
 }
 
+/// Line segment as ((x1, y1), (x2, y2))
+type Segment = ((f64, f64), (f64, f64));
+
+/// Segment with the pen color index it was drawn with
+type ColoredSegment = ((f64, f64), (f64, f64), usize);
+
+/// Distance, as a fraction of the step length, within which segments count
+/// as lying on the same line when removing overlaps
+const OVERLAP_TOLERANCE: f64 = 1e-6;
+
 /// Preset L-System configurations
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[pyclass(eq, eq_int)]
@@ -44,9 +59,7 @@ impl LSystemPreset {
             "plant2" => Ok(LSystemPreset::Plant2),
             "bushy" | "bushy_plant" => Ok(LSystemPreset::BushyPlant),
             "custom" => Ok(LSystemPreset::Custom),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(
-                "Invalid preset. Use 'koch', 'snowflake', 'sierpinski', 'dragon', 'hilbert', 'plant1', 'plant2', 'bushy', or 'custom'",
-            )),
+            _ => Err(AxiArtError::InvalidOption("Invalid preset. Use 'koch', 'snowflake', 'sierpinski', 'dragon', 'hilbert', 'plant1', 'plant2', 'bushy', or 'custom'".into()).into()),
         }
     }
 }
@@ -194,17 +207,62 @@ impl LSystemGenerator {
     /// Generate the L-System pattern
     ///
     /// Returns a list of ((x1, y1), (x2, y2)) tuples representing line segments
-    fn generate(&self) -> PyResult<Vec<((f64, f64), (f64, f64))>> {
-        // Expand the L-System string
-        let mut current = self.axiom.clone();
-        for _ in 0..self.iterations {
-            current = self.expand(&current);
+    ///
+    /// # Arguments
+    /// * `remove_overlaps` - Merge segments that retrace each other along the
+    ///   same line (as branches returning along their stem do) into one, so
+    ///   no stroke is inked twice
+    #[pyo3(signature = (remove_overlaps=false))]
+    fn generate(&self, remove_overlaps: bool) -> PyResult<Vec<Segment>> {
+        // Interpret the expanded string as turtle graphics
+        let lines: Vec<_> = self
+            .interpret_turtle(&self.expanded())
+            .into_iter()
+            .map(|(a, b, _)| (a, b))
+            .collect();
+
+        if remove_overlaps {
+            Ok(remove_collinear_overlaps(
+                &lines,
+                self.step_length * OVERLAP_TOLERANCE,
+            ))
+        } else {
+            Ok(lines)
         }
+    }
 
-        // Interpret as turtle graphics
-        let lines = self.interpret_turtle(&current);
+    /// Generate the L-System pattern with a pen color per segment
+    ///
+    /// Returns ((x1, y1), (x2, y2), color) tuples. The color index starts at
+    /// 0 and each `'` in the expanded string advances it by one; like the
+    /// position and heading it is saved by `[` and restored by `]`, so a
+    /// branch can switch pens without affecting its parent. Group segments by
+    /// color (e.g. modulo the number of pens) to plot each with its own pen.
+    fn generate_with_color(&self) -> PyResult<Vec<ColoredSegment>> {
+        Ok(self.interpret_turtle(&self.expanded()))
+    }
 
-        Ok(lines)
+    /// Generate the pattern after every expansion step, for build-up animations
+    ///
+    /// Returns one segment list per iteration count from 0 (the bare axiom)
+    /// to `iterations`, each in the same form as `generate`; the last entry
+    /// equals `generate()`.
+    fn generate_iterations(&self) -> PyResult<Vec<Vec<Segment>>> {
+        let mut frames = Vec::with_capacity(self.iterations + 1);
+        let mut current = self.axiom.clone();
+        for iteration in 0..=self.iterations {
+            if iteration > 0 {
+                current = self.expand(&current);
+            }
+            frames.push(
+                self.interpret_turtle(&current)
+                    .into_iter()
+                    .map(|(a, b, _)| (a, b))
+                    .collect(),
+            );
+        }
+
+        Ok(frames)
     }
 
     /// Get the width of the canvas
@@ -218,6 +276,19 @@ impl LSystemGenerator {
     fn height(&self) -> f64 {
         self.height
     }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl LSystemGenerator {
@@ -352,13 +423,25 @@ impl LSystemGenerator {
         result
     }
 
+    /// The axiom expanded `iterations` times
+    fn expanded(&self) -> String {
+        let mut current = self.axiom.clone();
+        for _ in 0..self.iterations {
+            current = self.expand(&current);
+        }
+        current
+    }
+
     /// Interpret L-System string as turtle graphics
-    fn interpret_turtle(&self, commands: &str) -> Vec<((f64, f64), (f64, f64))> {
+    ///
+    /// Returns each drawn segment with the color index it was drawn with.
+    fn interpret_turtle(&self, commands: &str) -> Vec<ColoredSegment> {
         let mut lines = Vec::new();
         let mut state = TurtleState {
             x: self.start_x,
             y: self.start_y,
             angle: self.start_angle,
+            color: 0,
         };
         let mut stack: Vec<TurtleState> = Vec::new();
 
@@ -370,7 +453,7 @@ impl LSystemGenerator {
                     let new_x = state.x + self.step_length * rad.cos();
                     let new_y = state.y - self.step_length * rad.sin(); // Negative because SVG y-axis goes down
 
-                    lines.push(((state.x, state.y), (new_x, new_y)));
+                    lines.push(((state.x, state.y), (new_x, new_y), state.color));
 
                     state.x = new_x;
                     state.y = new_y;
@@ -389,6 +472,14 @@ impl LSystemGenerator {
                     // Turn right
                     state.angle -= self.angle;
                 }
+                '|' => {
+                    // Turn around
+                    state.angle += 180.0;
+                }
+                '\'' => {
+                    // Switch to the next pen color
+                    state.color += 1;
+                }
                 '[' => {
                     // Push state
                     stack.push(state.clone());
@@ -408,3 +499,77 @@ impl LSystemGenerator {
         lines
     }
 }
+
+impl Generator for LSystemGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        self.generate(false).map(Paths::from_segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimize::unique_segments;
+
+    fn preset_generator(preset: LSystemPreset, iterations: usize) -> LSystemGenerator {
+        let (axiom, rules, angle, step_length, start_x, start_y, start_angle) =
+            LSystemGenerator::get_preset_params(preset, 297.0, 210.0);
+        LSystemGenerator {
+            width: 297.0,
+            height: 210.0,
+            preset,
+            axiom,
+            rules,
+            angle,
+            step_length,
+            iterations,
+            start_x,
+            start_y,
+            start_angle,
+        }
+    }
+
+    fn segments(generator: &LSystemGenerator) -> Vec<Segment> {
+        generator
+            .interpret_turtle(&generator.expanded())
+            .into_iter()
+            .map(|(a, b, _)| (a, b))
+            .collect()
+    }
+
+    /// Segments that coincide with an earlier one, in either direction
+    fn coincident_count(segments: &[Segment]) -> usize {
+        segments.len() - unique_segments(segments.to_vec(), 1e-6).len()
+    }
+
+    #[test]
+    fn retraced_branches_are_drawn_once() {
+        let generator = preset_generator(LSystemPreset::BushyPlant, 4);
+        let lines = segments(&generator);
+        let retraced = coincident_count(&lines);
+        assert!(retraced > 0);
+
+        // Every retraced segment is dropped, so the count strictly decreases
+        let cleaned = remove_collinear_overlaps(&lines, generator.step_length * OVERLAP_TOLERANCE);
+        assert_eq!(coincident_count(&cleaned), 0);
+        assert!(cleaned.len() <= lines.len() - retraced);
+    }
+
+    #[test]
+    fn partial_overlaps_merge_into_their_union() {
+        let lines = vec![
+            ((0.0, 0.0), (2.0, 0.0)),
+            ((3.0, 0.0), (1.0, 0.0)),
+            ((3.0, 0.0), (4.0, 0.0)),
+            ((0.0, 1.0), (2.0, 1.0)),
+        ];
+        assert_eq!(
+            remove_collinear_overlaps(&lines, 1e-9),
+            vec![
+                ((0.0, 0.0), (3.0, 0.0)),
+                ((3.0, 0.0), (4.0, 0.0)),
+                ((0.0, 1.0), (2.0, 1.0)),
+            ]
+        );
+    }
+}
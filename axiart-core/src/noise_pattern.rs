@@ -6,12 +6,43 @@
 //! - Parallel stippling generation
 //! - Zero overhead loops
 
+use crate::error::AxiArtError;
+use crate::noise_core::perlin_2d_with_gradient;
+use crate::optimize::segments_to_polylines;
+use crate::paths::{points_to_array, Bounds, Generator, Paths};
+use crate::seed::resolve_seed;
+use crate::smooth::{smooth_path, SmoothingMethod};
+use crate::threads;
+use noise::permutationtable::PermutationTable;
 use noise::{NoiseFn, Perlin};
+use numpy::PyArray2;
 use pyo3::prelude::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
 
+/// How `generate_hatching` turns the noise field into stroke angles
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HatchOrientation {
+    /// Angle proportional to the noise value
+    Value,
+    /// Along the contours, perpendicular to the analytic gradient
+    Gradient,
+}
+
+impl HatchOrientation {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s.to_lowercase().as_str() {
+            "value" => Ok(HatchOrientation::Value),
+            "gradient" => Ok(HatchOrientation::Gradient),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid orientation. Use 'value' or 'gradient'".into(),
+            )
+            .into()),
+        }
+    }
+}
+
 /// High-performance Noise Pattern Generator
 ///
 /// Generates contour lines, stippling, and cellular textures using Perlin noise.
@@ -27,6 +58,7 @@ pub struct NoisePatternGenerator {
     lacunarity: f64,
     seed: u32,
     noise: Perlin,
+    perm: PermutationTable,
 }
 
 #[pymethods]
@@ -50,7 +82,9 @@ impl NoisePatternGenerator {
         lacunarity: f64,
         seed: Option<u32>,
     ) -> Self {
-        let actual_seed = seed.unwrap_or_else(|| rand::thread_rng().gen());
+        let actual_seed = resolve_seed(seed.map(u64::from))
+            .map(|s| s as u32)
+            .unwrap_or_else(|| rand::thread_rng().gen());
         let noise = Perlin::new(actual_seed);
 
         NoisePatternGenerator {
@@ -62,6 +96,7 @@ impl NoisePatternGenerator {
             lacunarity,
             seed: actual_seed,
             noise,
+            perm: PermutationTable::new(actual_seed),
         }
     }
 
@@ -78,24 +113,11 @@ impl NoisePatternGenerator {
         min_value: f64,
         max_value: f64,
     ) -> PyResult<Vec<Vec<(f64, f64)>>> {
-        // Calculate grid dimensions
-        let x_samples = (self.width / resolution) as usize;
-        let y_samples = (self.height / resolution) as usize;
-
-        // Generate noise grid in batch (much faster than repeated calls)
-        let mut noise_grid = vec![vec![0.0; x_samples]; y_samples];
-        for i in 0..y_samples {
-            for j in 0..x_samples {
-                let x = j as f64 * resolution;
-                let y = i as f64 * resolution;
-                noise_grid[i][j] = self.get_noise_fbm(x, y);
-            }
-        }
+        let noise_grid = self.noise_grid(resolution);
 
         // Generate contour levels
         let mut all_segments = Vec::new();
-        for k in 0..num_levels {
-            let level = min_value + (max_value - min_value) * (k as f64) / (num_levels - 1) as f64;
+        for level in contour_levels(num_levels, min_value, max_value) {
             let segments = self.marching_squares(&noise_grid, level, resolution);
             all_segments.extend(segments);
         }
@@ -103,6 +125,50 @@ impl NoisePatternGenerator {
         Ok(all_segments)
     }
 
+    /// Generate contour lines joined into continuous polylines
+    ///
+    /// Runs `generate_contour_lines` and chains the marching-squares segments
+    /// end to end, so each contour is one polyline; contours that close on
+    /// themselves are returned as closed loops (first point repeated at the
+    /// end). `smooth` applies that many Catmull–Rom subdivision passes, treating
+    /// closed loops cyclically so no kink remains at their start; open
+    /// contours keep their endpoints on the canvas edge.
+    #[pyo3(signature = (num_levels=20, resolution=2.0, min_value=-1.0, max_value=1.0, smooth=0))]
+    fn generate_contour_paths(
+        &self,
+        num_levels: usize,
+        resolution: f64,
+        min_value: f64,
+        max_value: f64,
+        smooth: usize,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let noise_grid = self.noise_grid(resolution);
+
+        // Chain each level on its own: levels crossing the same cell can
+        // produce identical segments, which would otherwise link contours of
+        // different levels into junctions
+        let mut contours = Vec::new();
+        for level in contour_levels(num_levels, min_value, max_value) {
+            let segments = self
+                .marching_squares(&noise_grid, level, resolution)
+                .into_iter()
+                .map(|segment| (segment[0], segment[1]))
+                .collect();
+            // Crossings are uninterpolated edge midpoints, but neighboring
+            // cells place a shared one from their own corners (x + resolution
+            // on one side, (j + 1) * resolution on the other), which can
+            // differ by rounding, so match endpoints within a tolerance
+            contours.extend(segments_to_polylines(segments, resolution * 1e-6).0);
+        }
+
+        Ok(threads::install(|| {
+            contours
+                .into_par_iter()
+                .map(|contour| smooth_path(contour, smooth, SmoothingMethod::CatmullRom))
+                .collect()
+        }))
+    }
+
     /// Generate stippled texture using noise-based density mapping
     ///
     /// Returns list of (x, y) points for stippling
@@ -134,11 +200,13 @@ impl NoisePatternGenerator {
 
         // Filter by density map
         if parallel {
-            Ok(candidates
-                .par_iter()
-                .filter(|&&(x, y)| self.get_noise_fbm(x, y) > threshold)
-                .copied()
-                .collect())
+            Ok(threads::install(|| {
+                candidates
+                    .par_iter()
+                    .filter(|&&(x, y)| self.get_noise_fbm(x, y) > threshold)
+                    .copied()
+                    .collect()
+            }))
         } else {
             Ok(candidates
                 .iter()
@@ -148,18 +216,39 @@ impl NoisePatternGenerator {
         }
     }
 
+    /// Generate stippling as an (n, 2) NumPy array
+    ///
+    /// Same points as `generate_stippling`, returned without building a
+    /// Python tuple per point - much faster to transfer for large counts.
+    #[pyo3(signature = (num_points=5000, density_map=true, threshold=0.0, parallel=true))]
+    fn generate_stippling_array<'py>(
+        &self,
+        py: Python<'py>,
+        num_points: usize,
+        density_map: bool,
+        threshold: f64,
+        parallel: bool,
+    ) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        let points = self.generate_stippling(num_points, density_map, threshold, parallel)?;
+        Ok(points_to_array(py, &points))
+    }
+
     /// Generate cellular texture based on noise values
     ///
-    /// Returns list of paths (squares, circles as points, or hatching lines)
+    /// Returns (paths, points, radii): paths for squares or hatching lines,
+    /// and for circles the cell centers with a parallel list of dot radii.
+    /// A radius grows with the noise value, up to half the cell size, so the
+    /// dots plot as proportional tonal shading.
     #[pyo3(signature = (cell_size=5.0, threshold=0.0, pattern_type="squares"))]
     fn generate_cellular_texture(
         &self,
         cell_size: f64,
         threshold: f64,
         pattern_type: &str,
-    ) -> PyResult<(Vec<Vec<(f64, f64)>>, Vec<(f64, f64)>)> {
+    ) -> PyResult<(Vec<Vec<(f64, f64)>>, Vec<(f64, f64)>, Vec<f64>)> {
         let mut paths = Vec::new();
         let mut points = Vec::new();
+        let mut radii = Vec::new();
 
         let mut x = 0.0;
         while x < self.width {
@@ -180,7 +269,9 @@ impl NoisePatternGenerator {
                             paths.push(square);
                         }
                         "circles" => {
+                            let intensity = (noise_value + 1.0) / 2.0; // Normalize to [0, 1]
                             points.push((x + cell_size / 2.0, y + cell_size / 2.0));
+                            radii.push(intensity * cell_size / 2.0);
                         }
                         "hatching" => {
                             // Diagonal hatching based on noise intensity
@@ -200,19 +291,26 @@ impl NoisePatternGenerator {
             x += cell_size;
         }
 
-        Ok((paths, points))
+        Ok((paths, points, radii))
     }
 
     /// Generate hatching lines based on noise gradient direction
     ///
     /// Creates cross-hatching that follows the flow of the noise field.
-    #[pyo3(signature = (spacing=5.0, line_length=10.0, threshold=0.0))]
+    ///
+    /// `orientation` picks the stroke angle: "value" (the default) turns each
+    /// stroke by `noise_value * PI`, while "gradient" lays it perpendicular
+    /// to the analytic fBm gradient, along the local contour, so the hatching
+    /// wraps around hills and valleys like shaded relief.
+    #[pyo3(signature = (spacing=5.0, line_length=10.0, threshold=0.0, orientation="value"))]
     fn generate_hatching(
         &self,
         spacing: f64,
         line_length: f64,
         threshold: f64,
+        orientation: &str,
     ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let orientation = HatchOrientation::from_str(orientation)?;
         let mut lines = Vec::new();
 
         let mut y = 0.0;
@@ -222,8 +320,13 @@ impl NoisePatternGenerator {
                 let noise_value = self.get_noise_fbm(x, y);
 
                 if noise_value > threshold {
-                    // Get gradient direction for hatching angle
-                    let angle = noise_value * std::f64::consts::PI;
+                    let angle = match orientation {
+                        HatchOrientation::Value => noise_value * std::f64::consts::PI,
+                        HatchOrientation::Gradient => {
+                            let (grad_x, grad_y) = self.get_noise_fbm_gradient(x, y);
+                            grad_y.atan2(grad_x) + std::f64::consts::FRAC_PI_2
+                        }
+                    };
                     let dx = angle.cos() * line_length;
                     let dy = angle.sin() * line_length;
 
@@ -250,6 +353,27 @@ impl NoisePatternGenerator {
     fn height(&self) -> f64 {
         self.height
     }
+
+    /// Get the seed in use, including one drawn from entropy
+    ///
+    /// Pass it back as `seed` to reproduce a result you liked.
+    #[getter]
+    fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl NoisePatternGenerator {
@@ -276,6 +400,51 @@ impl NoisePatternGenerator {
         value / max_value
     }
 
+    /// Analytic gradient of `get_noise_fbm`
+    ///
+    /// Each octave samples noise at `(x / scale) * frequency`, so by the chain
+    /// rule its derivative contributes `amplitude * frequency / scale`.
+    fn get_noise_fbm_gradient(&self, x: f64, y: f64) -> (f64, f64) {
+        let mut grad_x = 0.0;
+        let mut grad_y = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max_value = 0.0;
+
+        for _ in 0..self.octaves {
+            let sample_x = (x / self.scale) * frequency;
+            let sample_y = (y / self.scale) * frequency;
+
+            let (_, dx, dy) = perlin_2d_with_gradient(&self.perm, sample_x, sample_y);
+            let chain = amplitude * frequency / self.scale;
+            grad_x += dx * chain;
+            grad_y += dy * chain;
+            max_value += amplitude;
+
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        (grad_x / max_value, grad_y / max_value)
+    }
+
+    /// Noise sampled every `resolution` units, as rows of values along x
+    fn noise_grid(&self, resolution: f64) -> Vec<Vec<f64>> {
+        let x_samples = (self.width / resolution) as usize;
+        let y_samples = (self.height / resolution) as usize;
+
+        // Generate noise grid in batch (much faster than repeated calls)
+        let mut noise_grid = vec![vec![0.0; x_samples]; y_samples];
+        for i in 0..y_samples {
+            for j in 0..x_samples {
+                let x = j as f64 * resolution;
+                let y = i as f64 * resolution;
+                noise_grid[i][j] = self.get_noise_fbm(x, y);
+            }
+        }
+        noise_grid
+    }
+
     /// Marching squares algorithm for contour extraction
     ///
     /// Efficient implementation with lookup table for cell configurations
@@ -354,3 +523,15 @@ impl NoisePatternGenerator {
         segments
     }
 }
+
+impl Generator for NoisePatternGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        self.generate_contour_lines(20, 2.0, -1.0, 1.0).map(Paths)
+    }
+}
+
+/// `num_levels` contour values spread evenly from `min_value` to `max_value`
+fn contour_levels(num_levels: usize, min_value: f64, max_value: f64) -> impl Iterator<Item = f64> {
+    (0..num_levels)
+        .map(move |k| min_value + (max_value - min_value) * (k as f64) / (num_levels - 1) as f64)
+}
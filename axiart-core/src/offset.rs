@@ -0,0 +1,241 @@
+//! Polygon offsetting
+//!
+//! Grows or shrinks closed shapes by a fixed distance for outlines, nested
+//! cells and shadows. Edges are shifted along their normals and rejoined with
+//! miters (beveled where a miter would spike). The raw offset ring can cross
+//! itself where the shape is too thin or concave for the distance, so it is
+//! split at its self-intersections and only the loops that bound the region
+//! it winds around positively are kept, the same cleanup a positive fill rule
+//! gives.
+
+use crate::error::AxiArtError;
+use crate::geometry::{point_segment_distance, signed_area, winding_number};
+use pyo3::prelude::*;
+
+/// Miters longer than this many offset distances are beveled
+const MITER_LIMIT: f64 = 2.0;
+
+/// Inset or outset a closed polygon
+///
+/// # Arguments
+/// * `polygon` - Vertex list, open or closed, in either winding order
+/// * `distance` - Offset distance; positive grows the shape, negative
+///   shrinks it
+///
+/// # Returns
+/// Closed loops (first point repeated at the end). An inset can split a
+/// shape into several pieces or remove it entirely (empty list); an outset
+/// can leave holes where concave parts close up.
+#[pyfunction]
+pub fn offset_polygon(polygon: Vec<(f64, f64)>, distance: f64) -> PyResult<Vec<Vec<(f64, f64)>>> {
+    let ring = clean_ring(&polygon);
+    if ring.len() < 3 {
+        return Err(
+            AxiArtError::InvalidParameter("Polygon needs at least 3 vertices".into()).into(),
+        );
+    }
+    Ok(offset_ring(ring, distance))
+}
+
+/// Vertices without the closing point or consecutive duplicates
+fn clean_ring(polygon: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut ring: Vec<(f64, f64)> = Vec::with_capacity(polygon.len());
+    for &point in polygon {
+        if ring.last() != Some(&point) {
+            ring.push(point);
+        }
+    }
+    while ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring
+}
+
+fn offset_ring(mut ring: Vec<(f64, f64)>, distance: f64) -> Vec<Vec<(f64, f64)>> {
+    // Counter-clockwise (y-up) so the outward normal is on the right
+    if signed_area(&ring) < 0.0 {
+        ring.reverse();
+    }
+    if distance == 0.0 {
+        ring.push(ring[0]);
+        return vec![ring];
+    }
+
+    let raw = miter_offset(&ring, distance);
+    let scale = distance.abs();
+    split_loops(&raw)
+        .into_iter()
+        .filter(|loop_| {
+            bounds_positive_region(loop_, &raw, scale) && clears_outline(loop_, &ring, scale)
+        })
+        .map(|mut loop_| {
+            loop_.push(loop_[0]);
+            loop_
+        })
+        .collect()
+}
+
+/// Shift every edge outward by `distance` and rejoin neighbors at their
+/// intersection, beveling over-long miters
+fn miter_offset(ring: &[(f64, f64)], distance: f64) -> Vec<(f64, f64)> {
+    let n = ring.len();
+    let normal = |i: usize| {
+        let (a, b) = (ring[i], ring[(i + 1) % n]);
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len = dx.hypot(dy);
+        (dy / len, -dx / len)
+    };
+
+    let mut out = Vec::with_capacity(n * 2);
+    for (i, &p) in ring.iter().enumerate() {
+        let (n0, n1) = (normal((i + n - 1) % n), normal(i));
+        let cos = n0.0 * n1.0 + n0.1 * n1.1;
+        // The miter point lies along the bisector at distance / cos(half angle)
+        let (bx, by) = (n0.0 + n1.0, n0.1 + n1.1);
+        let half_cos_sq = (1.0 + cos) / 2.0;
+
+        if half_cos_sq > 1.0 / (MITER_LIMIT * MITER_LIMIT) {
+            let k = distance / (2.0 * half_cos_sq);
+            out.push((p.0 + bx * k, p.1 + by * k));
+        } else {
+            out.push((p.0 + n0.0 * distance, p.1 + n0.1 * distance));
+            out.push((p.0 + n1.0 * distance, p.1 + n1.1 * distance));
+        }
+    }
+    out
+}
+
+/// Split a ring at its self-intersections into simple loops
+///
+/// Crossing points are inserted into both segments involved, then the ring
+/// is walked with a stack; returning to a crossing already on the stack pops
+/// the loop traced since.
+fn split_loops(ring: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+    let n = ring.len();
+    // Crossings along each segment as (t, crossing id)
+    let mut cuts: Vec<Vec<(f64, usize)>> = vec![Vec::new(); n];
+    let mut crossings = Vec::new();
+    for i in 0..n {
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue; // Adjacent through the closing vertex
+            }
+            let (a, b) = (ring[i], ring[(i + 1) % n]);
+            let (c, d) = (ring[j], ring[(j + 1) % n]);
+            if let Some((t, u)) = segment_intersection(a, b, c, d) {
+                let id = crossings.len();
+                crossings.push((a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t));
+                cuts[i].push((t, id));
+                cuts[j].push((u, id));
+            }
+        }
+    }
+
+    if crossings.is_empty() {
+        return vec![ring.to_vec()];
+    }
+
+    // Walk the ring starting just after the first crossing, so the stack
+    // begins on a crossing and every loop closes on one
+    let mut sequence: Vec<(Option<usize>, (f64, f64))> = Vec::new();
+    for i in 0..n {
+        sequence.push((None, ring[i]));
+        cuts[i].sort_by(|x, y| x.0.total_cmp(&y.0));
+        sequence.extend(cuts[i].iter().map(|&(_, id)| (Some(id), crossings[id])));
+    }
+    let start = sequence.iter().position(|(id, _)| id.is_some()).unwrap();
+    sequence.rotate_left(start);
+    // Revisit the starting crossing at the end to close the last loop
+    sequence.push(sequence[0]);
+
+    let mut loops = Vec::new();
+    let mut stack: Vec<(Option<usize>, (f64, f64))> = Vec::new();
+    let mut on_stack: Vec<Option<usize>> = vec![None; crossings.len()];
+    for (id, point) in sequence {
+        if let Some(id) = id {
+            if let Some(k) = on_stack[id] {
+                let popped = stack.split_off(k + 1);
+                for popped_id in popped.iter().filter_map(|&(id, _)| id) {
+                    on_stack[popped_id] = None;
+                }
+                let mut loop_ = vec![stack[k].1];
+                loop_.extend(popped.into_iter().map(|(_, p)| p));
+                if loop_.len() >= 3 {
+                    loops.push(loop_);
+                }
+                continue;
+            }
+            on_stack[id] = Some(stack.len());
+        }
+        stack.push((id, point));
+    }
+
+    loops
+}
+
+/// Parameters (t, u) where segments a->b and c->d meet
+///
+/// Both ranges are half-open, [0, 1), so a ring passing through another
+/// segment's vertex is cut there once rather than missed or counted twice.
+fn segment_intersection(
+    a: (f64, f64),
+    b: (f64, f64),
+    c: (f64, f64),
+    d: (f64, f64),
+) -> Option<(f64, f64)> {
+    let (rx, ry) = (b.0 - a.0, b.1 - a.1);
+    let (sx, sy) = (d.0 - c.0, d.1 - c.1);
+    let denom = rx * sy - ry * sx;
+    if denom.abs() < 1e-12 {
+        return None;
+    }
+    let (qx, qy) = (c.0 - a.0, c.1 - a.1);
+    let t = (qx * sy - qy * sx) / denom;
+    let u = (qx * ry - qy * rx) / denom;
+    const EPS: f64 = 1e-9;
+    (t > -EPS && t < 1.0 - EPS && u > -EPS && u < 1.0 - EPS).then_some((t.max(0.0), u.max(0.0)))
+}
+
+/// Whether a loop separates points the raw ring winds around positively from
+/// points it does not
+///
+/// Probes both sides of the loop's longest edge; loops split from one ring
+/// have the same winding along their whole length on each side.
+fn bounds_positive_region(loop_: &[(f64, f64)], raw: &[(f64, f64)], scale: f64) -> bool {
+    let n = loop_.len();
+    let (a, b) = (0..n)
+        .map(|i| (loop_[i], loop_[(i + 1) % n]))
+        .max_by(|(a0, b0), (a1, b1)| {
+            let l0 = (b0.0 - a0.0).hypot(b0.1 - a0.1);
+            let l1 = (b1.0 - a1.0).hypot(b1.1 - a1.1);
+            l0.total_cmp(&l1)
+        })
+        .unwrap();
+
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len = dx.hypot(dy);
+    if len == 0.0 {
+        return false;
+    }
+    let eps = (len * 1e-4).min(scale * 1e-4);
+    let (mx, my) = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+    let (nx, ny) = (-dy / len * eps, dx / len * eps);
+
+    let left = winding_number(mx + nx, my + ny, raw) > 0;
+    let right = winding_number(mx - nx, my - ny, raw) > 0;
+    left != right
+}
+
+/// Whether every loop vertex keeps at least the offset distance from the
+/// original outline
+///
+/// An inset wider than the shape can flip a part over without crossing
+/// itself (a square inset past its half-width turns into a smaller square);
+/// such loops sit too close to the outline and are dropped.
+fn clears_outline(loop_: &[(f64, f64)], ring: &[(f64, f64)], distance: f64) -> bool {
+    let n = ring.len();
+    let min_dist = distance * (1.0 - 1e-6);
+    loop_
+        .iter()
+        .all(|&p| (0..n).all(|i| point_segment_distance(p, ring[i], ring[(i + 1) % n]) >= min_dist))
+}
@@ -0,0 +1,494 @@
+//! Plot optimization for pen plotters
+//!
+//! Generators emit paths in whatever order they were produced, which can leave
+//! the pen travelling back and forth across the page between strokes. The
+//! functions here reorder and clean up path sets before plotting.
+
+use crate::geometry::polyline_length;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+type Segment = ((f64, f64), (f64, f64));
+
+/// Reorder paths to reduce pen-up travel
+///
+/// Greedy nearest-neighbor tour: starting from `start` (the plotter's home
+/// position by default), repeatedly draws the unvisited path whose start or
+/// end is closest to the pen, reversing the path when its end is closer.
+/// Endpoints are kept in a uniform spatial grid so each step only searches
+/// nearby cells. Empty paths are dropped.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `start` - Pen position before the first path
+/// * `return_savings` - Also return the pen-up travel saved
+///
+/// # Returns
+/// The reordered paths, or (paths, travel_saved) with `return_savings`, where
+/// travel_saved is the original minus the new total pen-up distance.
+#[pyfunction]
+#[pyo3(signature = (paths, start=(0.0, 0.0), return_savings=false))]
+pub fn reorder_paths(
+    py: Python<'_>,
+    paths: Paths,
+    start: (f64, f64),
+    return_savings: bool,
+) -> PyResult<PyObject> {
+    let paths: Vec<Vec<(f64, f64)>> = paths.0.into_iter().filter(|p| !p.is_empty()).collect();
+    let before = travel_distance(&paths, start);
+    let reordered = Paths(greedy_order(paths, start));
+
+    if return_savings {
+        let saved = before - travel_distance(&reordered.0, start);
+        Ok((reordered, saved).into_py(py))
+    } else {
+        Ok(reordered.into_py(py))
+    }
+}
+
+/// Chain line segments into polylines
+///
+/// Segments whose endpoints lie within `epsilon` of each other are treated as
+/// connected and followed end to end into maximal polylines. A polyline stops
+/// at dead ends and at junctions where three or more segments meet, which
+/// start new polylines. Chains that come back to their start are returned
+/// closed (first point repeated at the end). Shared vertices use the first
+/// matching endpoint seen, so joined polylines have no gaps; segments that
+/// collapse to a single point are dropped.
+///
+/// # Arguments
+/// * `segments` - Segments as ((x1, y1), (x2, y2)), e.g. from the dendrite,
+///   L-system or Voronoi generators
+/// * `epsilon` - Distance within which endpoints are considered the same
+#[pyfunction]
+#[pyo3(signature = (segments, epsilon=1e-6))]
+pub fn segments_to_polylines(segments: Vec<((f64, f64), (f64, f64))>, epsilon: f64) -> Paths {
+    let mut nodes = NodeIndex::new(epsilon);
+    let edges: Vec<(usize, usize)> = segments
+        .iter()
+        .map(|&(a, b)| (nodes.node(a), nodes.node(b)))
+        .filter(|(a, b)| a != b)
+        .collect();
+
+    let mut incident = vec![Vec::new(); nodes.points.len()];
+    for (idx, &(a, b)) in edges.iter().enumerate() {
+        incident[a].push(idx);
+        incident[b].push(idx);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut polylines = Vec::new();
+
+    // Walk from `from` along `edge`, continuing through degree-2 nodes
+    let walk = |from: usize, edge: usize, used: &mut [bool]| {
+        let mut path = vec![nodes.points[from]];
+        let (mut at, mut current) = (from, edge);
+        loop {
+            used[current] = true;
+            let (a, b) = edges[current];
+            at = if a == at { b } else { a };
+            path.push(nodes.points[at]);
+
+            if incident[at].len() != 2 {
+                break;
+            }
+            match incident[at].iter().copied().find(|&e| !used[e]) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        path
+    };
+
+    // Open chains first, starting from dead ends and junctions, so that
+    // chains are never split in the middle
+    for (node, edges_here) in incident.iter().enumerate() {
+        if edges_here.len() == 2 {
+            continue;
+        }
+        for &edge in edges_here {
+            if !used[edge] {
+                polylines.push(walk(node, edge, &mut used));
+            }
+        }
+    }
+
+    // Whatever remains forms closed loops of degree-2 nodes
+    for edge in 0..edges.len() {
+        if !used[edge] {
+            polylines.push(walk(edges[edge].0, edge, &mut used));
+        }
+    }
+
+    Paths(polylines)
+}
+
+/// Flatten polylines into two-point segments
+///
+/// The inverse of `segments_to_polylines`: every consecutive point pair
+/// becomes one `[(x0, y0), (x1, y1)]` segment, the layout matplotlib's
+/// `LineCollection` takes. Single-point paths contribute nothing.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+#[pyfunction]
+pub fn to_segments(paths: Paths) -> Vec<[(f64, f64); 2]> {
+    paths
+        .0
+        .iter()
+        .flat_map(|path| path.windows(2).map(|w| [w[0], w[1]]))
+        .collect()
+}
+
+/// Remove duplicate segments, in either direction
+///
+/// Two segments are duplicates when their endpoints match within `tolerance`
+/// in the same or reversed order, e.g. where overlaid patterns share edges.
+/// The first of each set of duplicates is kept, unchanged and in its original
+/// position. Endpoints are matched through a spatial grid rather than by
+/// rounding coordinates, so points straddling a rounding boundary still match.
+///
+/// # Arguments
+/// * `segments` - Segments as ((x1, y1), (x2, y2))
+/// * `tolerance` - Distance within which endpoints are the same point
+/// * `return_removed` - Also return the number of segments removed
+///
+/// # Returns
+/// The unique segments, or (segments, removed) with `return_removed`
+#[pyfunction]
+#[pyo3(signature = (segments, tolerance=1e-6, return_removed=false))]
+pub fn dedup_segments(
+    py: Python<'_>,
+    segments: Vec<Segment>,
+    tolerance: f64,
+    return_removed: bool,
+) -> PyObject {
+    let total = segments.len();
+    let unique = unique_segments(segments, tolerance);
+
+    if return_removed {
+        let removed = total - unique.len();
+        (unique, removed).into_py(py)
+    } else {
+        unique.into_py(py)
+    }
+}
+
+/// Segments with duplicates (within `tolerance`, either direction) removed,
+/// keeping the first occurrence
+///
+/// A tolerance of zero only matches bit-identical endpoints.
+pub(crate) fn unique_segments(segments: Vec<Segment>, tolerance: f64) -> Vec<Segment> {
+    let mut nodes = NodeIndex::new(tolerance);
+    let mut seen = HashSet::new();
+
+    segments
+        .into_iter()
+        .filter(|&(a, b)| {
+            let (a, b) = (nodes.node(a), nodes.node(b));
+            seen.insert((a.min(b), a.max(b)))
+        })
+        .collect()
+}
+
+/// Snap nearly coincident points together
+///
+/// Every point within `tolerance` of an earlier point is replaced by that
+/// point, across all paths, so endpoints separated by floating-point drift
+/// become exactly equal and joining utilities such as
+/// `segments_to_polylines` can merge them. Consecutive points that weld into
+/// one are collapsed.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `tolerance` - Distance within which points are merged
+#[pyfunction]
+#[pyo3(signature = (paths, tolerance=1e-6))]
+pub fn weld_points(paths: Paths, tolerance: f64) -> Paths {
+    let mut nodes = NodeIndex::new(tolerance);
+
+    Paths(
+        paths
+            .0
+            .into_iter()
+            .map(|path| {
+                let mut welded: Vec<(f64, f64)> = Vec::with_capacity(path.len());
+                for point in path {
+                    let id = nodes.node(point);
+                    let snapped = nodes.points[id];
+                    if welded.last() != Some(&snapped) {
+                        welded.push(snapped);
+                    }
+                }
+                welded
+            })
+            .collect(),
+    )
+}
+
+/// Total pen-up travel of a path set in its current order
+///
+/// Sums the straight-line distance from the end of each path to the start of
+/// the next. With `start` (e.g. the plotter's home position), the move to the
+/// first path is included too. Empty paths are skipped. Compare before and
+/// after `reorder_paths` to measure the improvement.
+#[pyfunction]
+#[pyo3(signature = (paths, start=None))]
+pub fn pen_up_distance(paths: Paths, start: Option<(f64, f64)>) -> f64 {
+    let paths: Vec<Vec<(f64, f64)>> = paths.0.into_iter().filter(|p| !p.is_empty()).collect();
+    match (start, paths.first()) {
+        (_, None) => 0.0,
+        (Some(start), _) => travel_distance(&paths, start),
+        (None, Some(first)) => travel_distance(&paths, first[0]),
+    }
+}
+
+/// Total pen-down (drawing) length of a path set
+#[pyfunction]
+pub fn pen_down_distance(paths: Paths) -> f64 {
+    paths.0.iter().map(|path| polyline_length(path)).sum()
+}
+
+/// Pen-up distance from `start` through each path in order
+fn travel_distance(paths: &[Vec<(f64, f64)>], start: (f64, f64)) -> f64 {
+    let mut pen = start;
+    let mut total = 0.0;
+    for path in paths {
+        let first = path[0];
+        total += (first.0 - pen.0).hypot(first.1 - pen.1);
+        pen = path[path.len() - 1];
+    }
+    total
+}
+
+/// Nearest-neighbor ordering of non-empty paths
+fn greedy_order(mut paths: Vec<Vec<(f64, f64)>>, start: (f64, f64)) -> Vec<Vec<(f64, f64)>> {
+    let n = paths.len();
+    if n < 2 {
+        return paths;
+    }
+
+    // Endpoint 0 is a path's first point, endpoint 1 its last
+    let endpoint = |paths: &[Vec<(f64, f64)>], idx: usize, end: usize| {
+        let path = &paths[idx];
+        if end == 0 {
+            path[0]
+        } else {
+            path[path.len() - 1]
+        }
+    };
+
+    let mut grid = EndpointGrid::new(
+        (0..n).flat_map(|idx| [endpoint(&paths, idx, 0), endpoint(&paths, idx, 1)]),
+        n,
+    );
+    for idx in 0..n {
+        grid.insert(idx, 0, endpoint(&paths, idx, 0));
+        grid.insert(idx, 1, endpoint(&paths, idx, 1));
+    }
+
+    let mut order = Vec::with_capacity(n);
+    let mut pen = start;
+    while let Some((idx, end)) = grid.nearest(pen) {
+        grid.remove(idx, 0, endpoint(&paths, idx, 0));
+        grid.remove(idx, 1, endpoint(&paths, idx, 1));
+        // Entering at the end means drawing the path backwards
+        pen = endpoint(&paths, idx, 1 - end);
+        order.push((idx, end == 1));
+    }
+
+    let mut reordered = Vec::with_capacity(n);
+    for (idx, reverse) in order {
+        let mut path = std::mem::take(&mut paths[idx]);
+        if reverse {
+            path.reverse();
+        }
+        reordered.push(path);
+    }
+    reordered
+}
+
+/// Merges points within a tolerance into shared nodes
+///
+/// Each new point is matched against existing nodes in the neighboring cells
+/// of a uniform grid (cell size = tolerance) and joins the first one within
+/// tolerance, or becomes a new node. A tolerance of zero matches exact
+/// coordinates only.
+struct NodeIndex {
+    tolerance: f64,
+    points: Vec<(f64, f64)>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl NodeIndex {
+    fn new(tolerance: f64) -> Self {
+        NodeIndex {
+            tolerance: tolerance.max(0.0),
+            points: Vec::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell_of(&self, (x, y): (f64, f64)) -> (i64, i64) {
+        if self.tolerance > 0.0 {
+            (
+                (x / self.tolerance).floor() as i64,
+                (y / self.tolerance).floor() as i64,
+            )
+        } else {
+            // Exact matching: key by the coordinates' bit patterns
+            ((x + 0.0).to_bits() as i64, (y + 0.0).to_bits() as i64)
+        }
+    }
+
+    /// Node id for a point, creating a node if none is within tolerance
+    fn node(&mut self, point: (f64, f64)) -> usize {
+        let (cx, cy) = self.cell_of(point);
+        let reach = if self.tolerance > 0.0 { 1 } else { 0 };
+
+        for gy in cy - reach..=cy + reach {
+            for gx in cx - reach..=cx + reach {
+                if let Some(ids) = self.cells.get(&(gx, gy)) {
+                    for &id in ids {
+                        let (px, py) = self.points[id];
+                        if (px - point.0).hypot(py - point.1) <= self.tolerance {
+                            return id;
+                        }
+                    }
+                }
+            }
+        }
+
+        let id = self.points.len();
+        self.points.push(point);
+        self.cells.entry((cx, cy)).or_default().push(id);
+        id
+    }
+}
+
+/// Grid entry: path index, endpoint (0 = first point, 1 = last) and position
+type Endpoint = (usize, usize, (f64, f64));
+
+/// Uniform grid of path endpoints for nearest-endpoint queries
+struct EndpointGrid {
+    min_x: f64,
+    min_y: f64,
+    cell_size: f64,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<Endpoint>>,
+}
+
+impl EndpointGrid {
+    /// Size the grid over the points' bounding box with about one path per cell
+    fn new(points: impl Iterator<Item = (f64, f64)>, num_paths: usize) -> Self {
+        let (min_x, min_y, max_x, max_y) = points.fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(x0, y0, x1, y1), (x, y)| (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+        );
+
+        let span = (max_x - min_x).max(max_y - min_y).max(1e-9);
+        let cell_size = span / (num_paths as f64).sqrt().ceil();
+        let cols = ((max_x - min_x) / cell_size).floor() as usize + 1;
+        let rows = ((max_y - min_y) / cell_size).floor() as usize + 1;
+
+        EndpointGrid {
+            min_x,
+            min_y,
+            cell_size,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+        }
+    }
+
+    /// Cell coordinates of a point, which may lie outside the grid (queries
+    /// such as the start position can be anywhere)
+    fn cell_of(&self, (x, y): (f64, f64)) -> (i64, i64) {
+        (
+            ((x - self.min_x) / self.cell_size).floor() as i64,
+            ((y - self.min_y) / self.cell_size).floor() as i64,
+        )
+    }
+
+    fn index(&self, point: (f64, f64)) -> usize {
+        let (cx, cy) = self.cell_of(point);
+        let cx = cx.clamp(0, self.cols as i64 - 1) as usize;
+        let cy = cy.clamp(0, self.rows as i64 - 1) as usize;
+        cy * self.cols + cx
+    }
+
+    fn insert(&mut self, idx: usize, end: usize, point: (f64, f64)) {
+        let cell = self.index(point);
+        self.cells[cell].push((idx, end, point));
+    }
+
+    fn remove(&mut self, idx: usize, end: usize, point: (f64, f64)) {
+        let cell = self.index(point);
+        if let Some(pos) = self.cells[cell]
+            .iter()
+            .position(|&(i, e, _)| (i, e) == (idx, end))
+        {
+            self.cells[cell].swap_remove(pos);
+        }
+    }
+
+    /// Closest remaining endpoint to `point` as (path index, endpoint)
+    ///
+    /// Searches rings of cells outward until no unvisited ring can hold
+    /// anything closer. Ties go to the lowest path index, then the start.
+    fn nearest(&self, point: (f64, f64)) -> Option<(usize, usize)> {
+        let (cx, cy) = self.cell_of(point);
+        let (cols, rows) = (self.cols as i64, self.rows as i64);
+        // Ring that covers the whole grid from the query cell
+        let max_ring = [cx, cols - 1 - cx, cy, rows - 1 - cy]
+            .iter()
+            .map(|d| d.abs())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let mut best: Option<(f64, usize, usize)> = None;
+        for ring in 0..=max_ring {
+            // Everything in this ring or beyond is at least this far away
+            let ring_distance = (ring - 1).max(0) as f64 * self.cell_size;
+            if best.is_some_and(|(d, _, _)| d < ring_distance) {
+                break;
+            }
+
+            for gy in (cy - ring)..=(cy + ring) {
+                if gy < 0 || gy >= rows {
+                    continue;
+                }
+                let on_edge_row = gy == cy - ring || gy == cy + ring;
+                let step = if on_edge_row { 1 } else { (2 * ring).max(1) };
+                let mut gx = cx - ring;
+                while gx <= cx + ring {
+                    if gx >= 0 && gx < cols {
+                        for &(idx, end, (px, py)) in &self.cells[(gy * cols + gx) as usize] {
+                            let d = (px - point.0).hypot(py - point.1);
+                            let better = match best {
+                                None => true,
+                                Some((best_d, best_idx, best_end)) => {
+                                    d < best_d || (d == best_d && (idx, end) < (best_idx, best_end))
+                                }
+                            };
+                            if better {
+                                best = Some((d, idx, end));
+                            }
+                        }
+                    }
+                    gx += step;
+                }
+            }
+        }
+
+        best.map(|(_, idx, end)| (idx, end))
+    }
+}
@@ -0,0 +1,100 @@
+//! Common path representation shared by generators and utilities
+//!
+//! Generators return points, segments, polylines or tuples of these. `Paths`
+//! is the one shape everything can be reduced to - a list of polylines - and
+//! is what the export, clipping and optimization utilities accept. The
+//! `Generator` trait produces it from any generator's default output.
+//!
+//! Large outputs can also be handed to Python as NumPy arrays, skipping the
+//! per-point tuple conversion: point sets as an (n, 2) array and polyline
+//! sets in a ragged layout of stacked coordinates plus offsets.
+
+use numpy::{PyArray1, PyArray2, PyArrayMethods};
+use pyo3::prelude::*;
+
+/// Ragged NumPy form of a path set: stacked (n, 2) coordinates and the
+/// offsets where each polyline starts
+pub type PathArrays<'py> = (Bound<'py, PyArray2<f64>>, Bound<'py, PyArray1<usize>>);
+
+/// Axis-aligned extent as (min_x, min_y, max_x, max_y)
+pub type Bounds = (f64, f64, f64, f64);
+
+/// A set of polylines, each a list of (x, y) points
+///
+/// Converts to and from a Python list of lists of (x, y) tuples, so Python
+/// callers pass and receive plain lists.
+#[derive(Debug, Clone, Default, PartialEq, FromPyObject)]
+pub struct Paths(pub Vec<Vec<(f64, f64)>>);
+
+impl Paths {
+    /// Two-point polylines from ((x1, y1), (x2, y2)) segments
+    pub fn from_segments(segments: Vec<((f64, f64), (f64, f64))>) -> Self {
+        Paths(segments.into_iter().map(|(a, b)| vec![a, b]).collect())
+    }
+
+    /// Extent of every point, or None when there are no points
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut points = self.0.iter().flatten();
+        let &(x, y) = points.next()?;
+        Some(points.fold((x, y, x, y), |(x0, y0, x1, y1), &(x, y)| {
+            (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+        }))
+    }
+
+    /// Ragged NumPy layout: all points stacked into an (n, 2) array, plus an
+    /// offsets array of length `len + 1` where polyline i is
+    /// `coords[offsets[i]:offsets[i + 1]]`
+    pub fn into_arrays(self, py: Python<'_>) -> PathArrays<'_> {
+        let mut offsets = Vec::with_capacity(self.0.len() + 1);
+        offsets.push(0);
+        let mut total = 0;
+        for path in &self.0 {
+            total += path.len();
+            offsets.push(total);
+        }
+
+        let points: Vec<(f64, f64)> = self.0.into_iter().flatten().collect();
+        (
+            points_to_array(py, &points),
+            PyArray1::from_vec_bound(py, offsets),
+        )
+    }
+}
+
+/// (n, 2) NumPy array of x, y rows from a point list
+pub fn points_to_array<'py>(py: Python<'py>, points: &[(f64, f64)]) -> Bound<'py, PyArray2<f64>> {
+    let flat: Vec<f64> = points.iter().flat_map(|&(x, y)| [x, y]).collect();
+    let array = PyArray1::from_vec_bound(py, flat);
+    array.reshape([points.len(), 2]).unwrap()
+}
+
+impl From<Vec<Vec<(f64, f64)>>> for Paths {
+    fn from(paths: Vec<Vec<(f64, f64)>>) -> Self {
+        Paths(paths)
+    }
+}
+
+impl IntoPy<PyObject> for Paths {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.0.into_py(py)
+    }
+}
+
+/// A pattern generator whose output can be drawn as polylines
+///
+/// Implementations run the generator's main method with its default
+/// parameters and flatten the result: segments become two-point polylines and
+/// bare points (such as dendrite nodes or Voronoi sites) are left out. Takes
+/// `&mut self` because several generators advance their random state.
+pub trait Generator {
+    /// Generate with default parameters and return every stroke as a polyline
+    fn paths(&mut self) -> PyResult<Paths>;
+
+    /// Extent of `paths()`, or None when it is empty
+    ///
+    /// Runs the generator once by default; generators whose extent is known
+    /// from their parameters override this to skip generation.
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Ok(self.paths()?.bounds())
+    }
+}
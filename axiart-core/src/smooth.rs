@@ -0,0 +1,140 @@
+//! Polyline smoothing
+//!
+//! Softens faceted output such as marching-squares contours or joined Truchet
+//! paths. Closed paths (first point repeated at the end) are smoothed as loops
+//! so no seam or corner remains where they start.
+
+use crate::error::AxiArtError;
+use crate::paths::Paths;
+use crate::threads;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+/// Smoothing algorithm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SmoothingMethod {
+    /// Corner cutting: each corner is replaced by points at 1/4 and 3/4 of
+    /// its adjacent segments (converges to a quadratic B-spline)
+    Chaikin,
+    /// Interpolating spline through the original points; each iteration adds
+    /// a spline midpoint between every pair of points
+    CatmullRom,
+}
+
+impl SmoothingMethod {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s.to_lowercase().as_str() {
+            "chaikin" => Ok(SmoothingMethod::Chaikin),
+            "catmull_rom" | "catmullrom" | "catmull-rom" => Ok(SmoothingMethod::CatmullRom),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid smoothing method. Use 'chaikin' or 'catmull_rom'".into(),
+            )
+            .into()),
+        }
+    }
+}
+
+/// Smooth polylines
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `iterations` - Smoothing passes; each roughly doubles the point count
+/// * `method` - "chaikin" (rounds corners, endpoints kept) or "catmull_rom"
+///   (passes through every original point)
+///
+/// # Returns
+/// Smoothed polylines. Closed paths stay closed; paths with fewer than three
+/// points are returned unchanged.
+#[pyfunction]
+#[pyo3(signature = (paths, iterations=2, method="chaikin"))]
+pub fn smooth_paths(paths: Paths, iterations: usize, method: &str) -> PyResult<Paths> {
+    let method = SmoothingMethod::from_str(method)?;
+
+    Ok(Paths(threads::install(|| {
+        paths
+            .0
+            .into_par_iter()
+            .map(|path| smooth_path(path, iterations, method))
+            .collect()
+    })))
+}
+
+/// Smooth one polyline, treating it as a loop when its ends coincide
+pub(crate) fn smooth_path(
+    path: Vec<(f64, f64)>,
+    iterations: usize,
+    method: SmoothingMethod,
+) -> Vec<(f64, f64)> {
+    if path.len() < 3 {
+        return path;
+    }
+
+    let closed = path.first() == path.last();
+    let mut points = path;
+    if closed {
+        points.pop();
+    }
+
+    for _ in 0..iterations {
+        points = match method {
+            SmoothingMethod::Chaikin => chaikin_step(&points, closed),
+            SmoothingMethod::CatmullRom => catmull_rom_step(&points, closed),
+        };
+    }
+
+    if closed {
+        points.push(points[0]);
+    }
+    points
+}
+
+/// One round of Chaikin corner cutting
+fn chaikin_step(points: &[(f64, f64)], closed: bool) -> Vec<(f64, f64)> {
+    let n = points.len();
+    let segments = if closed { n } else { n - 1 };
+    let mut out = Vec::with_capacity(2 * n);
+
+    if !closed {
+        out.push(points[0]);
+    }
+    for i in 0..segments {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        out.push((0.75 * a.0 + 0.25 * b.0, 0.75 * a.1 + 0.25 * b.1));
+        out.push((0.25 * a.0 + 0.75 * b.0, 0.25 * a.1 + 0.75 * b.1));
+    }
+    if !closed {
+        out.push(points[n - 1]);
+    }
+
+    out
+}
+
+/// One round of Catmull–Rom subdivision: keep every point and insert the
+/// uniform spline's midpoint on each segment
+fn catmull_rom_step(points: &[(f64, f64)], closed: bool) -> Vec<(f64, f64)> {
+    let n = points.len();
+    let segments = if closed { n } else { n - 1 };
+    // Neighbor lookup that wraps for loops and repeats the ends otherwise
+    let at = |i: isize| {
+        if closed {
+            points[i.rem_euclid(n as isize) as usize]
+        } else {
+            points[i.clamp(0, n as isize - 1) as usize]
+        }
+    };
+
+    let mut out = Vec::with_capacity(2 * n);
+    for i in 0..segments as isize {
+        let (p0, p1, p2, p3) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+        out.push(p1);
+        out.push((
+            (-p0.0 + 9.0 * p1.0 + 9.0 * p2.0 - p3.0) / 16.0,
+            (-p0.1 + 9.0 * p1.1 + 9.0 * p2.1 - p3.1) / 16.0,
+        ));
+    }
+    if !closed {
+        out.push(points[n - 1]);
+    }
+
+    out
+}
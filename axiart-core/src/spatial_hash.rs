@@ -0,0 +1,279 @@
+//! Uniform grid spatial hash
+//!
+//! Buckets points into square cells keyed by integer cell coordinates, so
+//! neighbor queries only look at the handful of cells around the query
+//! point. Unlike the KD-tree it accepts points one at a time, which suits
+//! incremental growth such as the dendrite aggregation, and it has no
+//! capacity limits however clustered the points get.
+
+use crate::error::AxiArtError;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// Incremental spatial hash for neighbor queries on a growing point set
+///
+/// Points are identified by their insertion index. Queries are fastest when
+/// `cell_size` is close to the typical query radius.
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import SpatialHash
+///
+/// grid = SpatialHash(cell_size=5.0)
+/// for x, y in points:
+///     grid.insert(x, y)
+/// neighbors = grid.within_radius(10.0, 10.0, 5.0)
+/// ```
+#[pyclass]
+pub struct SpatialHash {
+    cell_size: f64,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    points: Vec<(f64, f64)>,
+    /// Inclusive range of occupied cells, as (min_x, min_y, max_x, max_y)
+    extent: Option<(i32, i32, i32, i32)>,
+}
+
+#[pymethods]
+impl SpatialHash {
+    /// Create an empty spatial hash
+    ///
+    /// # Arguments
+    /// * `cell_size` - Side length of the square grid cells (must be positive)
+    #[new]
+    #[pyo3(signature = (cell_size=1.0))]
+    fn py_new(cell_size: f64) -> PyResult<Self> {
+        if cell_size <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("cell_size must be positive".into()).into());
+        }
+        Ok(Self::new(cell_size))
+    }
+
+    /// Add a point, returning its index
+    pub fn insert(&mut self, x: f64, y: f64) -> usize {
+        let idx = self.points.len();
+        let cell = self.cell(x, y);
+        self.cells.entry(cell).or_default().push(idx);
+        self.points.push((x, y));
+        self.extent = Some(match self.extent {
+            None => (cell.0, cell.1, cell.0, cell.1),
+            Some((x0, y0, x1, y1)) => (
+                x0.min(cell.0),
+                y0.min(cell.1),
+                x1.max(cell.0),
+                y1.max(cell.1),
+            ),
+        });
+        idx
+    }
+
+    /// Index of the point closest to (x, y), or None when empty
+    ///
+    /// Searches outward ring by ring, so it is exact however far away the
+    /// nearest point is. Only the part of each ring inside the occupied cells
+    /// is checked, and once that is more cells than there are points, every
+    /// point is checked directly instead. Ties go to the lowest index.
+    fn nearest(&self, x: f64, y: f64) -> Option<usize> {
+        let (x0, y0, x1, y1) = self.extent_i64()?;
+        let (cx, cy) = self.cell_i64(x, y);
+        // Rings before the first and after the last hold no points
+        let first_ring = (x0 - cx).max(cx - x1).max(y0 - cy).max(cy - y1).max(0);
+        let last_ring = (cx - x0).max(x1 - cx).max(cy - y0).max(y1 - cy).max(0);
+
+        let mut best: Option<(usize, f64)> = None;
+        for ring in first_ring..=last_ring {
+            // Offsets of the ring's bounding square that fall in the extent
+            let (dx0, dx1) = ((x0 - cx).max(-ring), (x1 - cx).min(ring));
+            let (dy0, dy1) = ((y0 - cy).max(-ring), (y1 - cy).min(ring));
+            if 2 * ((dx1 - dx0 + 1) + (dy1 - dy0 + 1)) as usize > self.points.len() {
+                return self.nearest_linear(x, y).map(|(idx, _)| idx);
+            }
+
+            for dx in dx0..=dx1 {
+                if dx.abs() == ring {
+                    for dy in dy0..=dy1 {
+                        self.scan_cell(cx + dx, cy + dy, x, y, &mut best);
+                    }
+                } else {
+                    // Only the top and bottom cells of inner columns are on the ring
+                    for dy in [-ring, ring] {
+                        if (dy0..=dy1).contains(&dy) {
+                            self.scan_cell(cx + dx, cy + dy, x, y, &mut best);
+                        }
+                    }
+                }
+            }
+            // Any point in a later ring is at least `ring` cells away
+            let reach = ring as f64 * self.cell_size;
+            if best.is_some_and(|(_, dist_sq)| dist_sq <= reach * reach) {
+                break;
+            }
+        }
+        best.map(|(idx, _)| idx)
+    }
+
+    /// Indices of every point within `radius` of (x, y), in insertion order
+    fn within_radius(&self, x: f64, y: f64, radius: f64) -> PyResult<Vec<usize>> {
+        if radius < 0.0 || radius.is_nan() {
+            return Err(AxiArtError::InvalidParameter("radius must be non-negative".into()).into());
+        }
+        let radius_sq = radius * radius;
+        let within =
+            |&(px, py): &(f64, f64)| (px - x) * (px - x) + (py - y) * (py - y) <= radius_sq;
+
+        let Some((x0, y0, x1, y1)) = self.cells_in_reach(x, y, radius) else {
+            return Ok(Vec::new());
+        };
+        if self.more_cells_than_points(x0, y0, x1, y1) {
+            return Ok((0..self.points.len())
+                .filter(|&idx| within(&self.points[idx]))
+                .collect());
+        }
+
+        let mut found = Vec::new();
+        for cx in x0..=x1 {
+            for cy in y0..=y1 {
+                if let Some(indices) = self.cells.get(&(cx as i32, cy as i32)) {
+                    found.extend(
+                        indices
+                            .iter()
+                            .copied()
+                            .filter(|&idx| within(&self.points[idx])),
+                    );
+                }
+            }
+        }
+        found.sort_unstable();
+        Ok(found)
+    }
+
+    /// Coordinates of the point with the given index
+    fn point(&self, idx: usize) -> PyResult<(f64, f64)> {
+        self.points.get(idx).copied().ok_or_else(|| {
+            AxiArtError::InvalidParameter(format!("No point with index {}", idx)).into()
+        })
+    }
+
+    /// Side length of the grid cells
+    #[getter]
+    fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    fn __len__(&self) -> usize {
+        self.points.len()
+    }
+}
+
+impl SpatialHash {
+    pub(crate) fn new(cell_size: f64) -> Self {
+        SpatialHash {
+            cell_size,
+            cells: HashMap::new(),
+            points: Vec::new(),
+            extent: None,
+        }
+    }
+
+    /// Convert world coordinates to grid cell coordinates
+    ///
+    /// The cast saturates, so coordinates beyond the i32 cell range share
+    /// the outermost cells rather than wrapping around.
+    #[inline]
+    fn cell(&self, x: f64, y: f64) -> (i32, i32) {
+        (
+            (x / self.cell_size).floor() as i32,
+            (y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// `cell` widened to i64, so offsets between any two cells fit
+    #[inline]
+    fn cell_i64(&self, x: f64, y: f64) -> (i64, i64) {
+        let (cx, cy) = self.cell(x, y);
+        (cx as i64, cy as i64)
+    }
+
+    /// `extent` widened to i64
+    fn extent_i64(&self) -> Option<(i64, i64, i64, i64)> {
+        self.extent
+            .map(|(x0, y0, x1, y1)| (x0 as i64, y0 as i64, x1 as i64, y1 as i64))
+    }
+
+    /// Occupied-extent cells that can hold a point within `radius` of
+    /// (x, y), as an inclusive (min_x, min_y, max_x, max_y) range, or None
+    /// when there are none
+    fn cells_in_reach(&self, x: f64, y: f64, radius: f64) -> Option<(i64, i64, i64, i64)> {
+        let (x0, y0, x1, y1) = self.extent_i64()?;
+        let (cx, cy) = self.cell_i64(x, y);
+        // Any reach past the i32 cell range covers the whole extent
+        let reach = (radius / self.cell_size).ceil().clamp(0.0, u32::MAX as f64) as i64;
+
+        let range = (
+            (cx - reach).max(x0),
+            (cy - reach).max(y0),
+            (cx + reach).min(x1),
+            (cy + reach).min(y1),
+        );
+        (range.0 <= range.2 && range.1 <= range.3).then_some(range)
+    }
+
+    /// Whether an inclusive cell range is larger than the point count, so a
+    /// linear scan over the points is cheaper than visiting the cells
+    fn more_cells_than_points(&self, x0: i64, y0: i64, x1: i64, y1: i64) -> bool {
+        ((x1 - x0 + 1) as u128) * ((y1 - y0 + 1) as u128) > self.points.len() as u128
+    }
+
+    /// Nearest point closer than `radius`, as (index, distance squared)
+    ///
+    /// Only the occupied cells that can hold such a point are checked, so
+    /// with `radius` at most `cell_size` this is at most the 3x3 block around
+    /// the query. Ties go to the lowest index.
+    pub(crate) fn nearest_within(&self, x: f64, y: f64, radius: f64) -> Option<(usize, f64)> {
+        let (x0, y0, x1, y1) = self.cells_in_reach(x, y, radius)?;
+        let best = if self.more_cells_than_points(x0, y0, x1, y1) {
+            self.nearest_linear(x, y)
+        } else {
+            let mut best = None;
+            for cx in x0..=x1 {
+                for cy in y0..=y1 {
+                    self.scan_cell(cx, cy, x, y, &mut best);
+                }
+            }
+            best
+        };
+        best.filter(|&(_, dist_sq)| dist_sq < radius * radius)
+    }
+
+    /// Closest point to (x, y) by checking every point, as (index, distance
+    /// squared). Ties go to the lowest index.
+    fn nearest_linear(&self, x: f64, y: f64) -> Option<(usize, f64)> {
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(idx, &(px, py))| (idx, (px - x) * (px - x) + (py - y) * (py - y)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// Update `best` with any closer point in one cell, keeping the lower
+    /// index on ties
+    ///
+    /// Cells outside the i32 range hold no points, so they are skipped.
+    fn scan_cell(&self, cx: i64, cy: i64, x: f64, y: f64, best: &mut Option<(usize, f64)>) {
+        let (Ok(cx), Ok(cy)) = (i32::try_from(cx), i32::try_from(cy)) else {
+            return;
+        };
+        let Some(indices) = self.cells.get(&(cx, cy)) else {
+            return;
+        };
+        for &idx in indices {
+            let (px, py) = self.points[idx];
+            let dist_sq = (px - x) * (px - x) + (py - y) * (py - y);
+            if best.is_none_or(|(best_idx, best_dist)| {
+                dist_sq < best_dist || (dist_sq == best_dist && idx < best_idx)
+            }) {
+                *best = Some((idx, dist_sq));
+            }
+        }
+    }
+}
@@ -3,6 +3,10 @@
 //! Fast geometric calculations for spirals and concentric circles.
 //! Already fast in Python (using numpy), but Rust eliminates all overhead.
 
+use crate::error::AxiArtError;
+use crate::geometry::{clip_polyline_to_polygon, polygon_centroid};
+use crate::paths::{Bounds, Generator, Paths};
+use noise::{NoiseFn, Perlin};
 use pyo3::prelude::*;
 use std::f64::consts::PI;
 
@@ -23,9 +27,23 @@ impl SpiralType {
             "archimedean" => Ok(SpiralType::Archimedean),
             "logarithmic" => Ok(SpiralType::Logarithmic),
             "concentric" => Ok(SpiralType::Concentric),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(
-                "Invalid spiral type",
-            )),
+            _ => Err(AxiArtError::InvalidOption("Invalid spiral type".into()).into()),
+        }
+    }
+}
+
+/// Spiral type argument accepted from Python: a `SpiralType` or its name
+#[derive(FromPyObject)]
+pub enum SpiralTypeArg {
+    Type(SpiralType),
+    Name(String),
+}
+
+impl SpiralTypeArg {
+    fn resolve(self) -> PyResult<SpiralType> {
+        match self {
+            SpiralTypeArg::Type(t) => Ok(t),
+            SpiralTypeArg::Name(name) => SpiralType::from_str(&name),
         }
     }
 }
@@ -34,6 +52,9 @@ impl SpiralType {
 ///
 /// Fast geometric spiral generation - already efficient in Python,
 /// but Rust eliminates all interpreter overhead.
+///
+/// `spiral_type` accepts either a `SpiralType` value or its name
+/// ("archimedean", "logarithmic", "concentric").
 #[pyclass]
 pub struct SpiralGenerator {
     width: f64,
@@ -53,7 +74,7 @@ impl SpiralGenerator {
         center=None,
         num_revolutions=20,
         points_per_revolution=100,
-        spiral_type="archimedean"
+        spiral_type=SpiralTypeArg::Type(SpiralType::Archimedean)
     ))]
     fn new(
         width: f64,
@@ -61,9 +82,9 @@ impl SpiralGenerator {
         center: Option<(f64, f64)>,
         num_revolutions: usize,
         points_per_revolution: usize,
-        spiral_type: &str,
+        spiral_type: SpiralTypeArg,
     ) -> PyResult<Self> {
-        let stype = SpiralType::from_str(spiral_type)?;
+        let stype = spiral_type.resolve()?;
         let actual_center = center.unwrap_or((width / 2.0, height / 2.0));
 
         Ok(SpiralGenerator {
@@ -79,13 +100,46 @@ impl SpiralGenerator {
     /// Generate spiral pattern(s)
     ///
     /// Returns list of spiral paths
+    ///
+    /// Points are uniform in angle by default. With `uniform_arc_length`,
+    /// each spiral is resampled so consecutive points are equidistant along
+    /// the curve, keeping line quality constant from center to rim.
+    ///
+    /// `noise_displacement` pushes each point along its outward (radial)
+    /// normal by a seeded Perlin lookup of that amplitude, turning rigid
+    /// spirals into hand-drawn looking ones. At 0.0 the output is unchanged.
+    ///
+    /// For the concentric type, the angle keeps running while the radius
+    /// steps up once per revolution, so by default each ring stops one point
+    /// short of closing and the step to the next ring is a slanted chord: a
+    /// staircase spiral. With `concentric_connected`, every ring is drawn as
+    /// a full closed circle and the next one starts at the same angle, so
+    /// consecutive rings are joined by a short radial jog instead. Other
+    /// spiral types ignore it.
+    ///
+    /// Spirals wind counterclockwise (increasing angle) from the center
+    /// outward by default. `clockwise` mirrors each spiral across its starting
+    /// direction so it winds the other way from the same start point, and
+    /// `inward` reverses the point order so each stroke plots from the rim
+    /// to the center.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
     #[pyo3(signature = (
         start_radius=5.0,
         end_radius=None,
         rotation_offset=0.0,
         growth_factor=1.0,
         num_spirals=1,
-        angular_offset=0.0
+        angular_offset=0.0,
+        uniform_arc_length=false,
+        noise_displacement=0.0,
+        noise_scale=20.0,
+        noise_seed=0,
+        concentric_connected=false,
+        clockwise=false,
+        inward=false
     ))]
     fn generate(
         &self,
@@ -95,16 +149,16 @@ impl SpiralGenerator {
         growth_factor: f64,
         num_spirals: usize,
         angular_offset: f64,
+        uniform_arc_length: bool,
+        noise_displacement: f64,
+        noise_scale: f64,
+        noise_seed: u32,
+        concentric_connected: bool,
+        clockwise: bool,
+        inward: bool,
     ) -> PyResult<Vec<Vec<(f64, f64)>>> {
         // Calculate max radius if not provided
-        let max_radius = end_radius.unwrap_or_else(|| {
-            let dx = [self.center.0, self.width - self.center.0];
-            let dy = [self.center.1, self.height - self.center.1];
-            dx.iter()
-                .chain(dy.iter())
-                .fold(f64::INFINITY, |a, &b| a.min(b))
-                * 0.9
-        });
+        let max_radius = end_radius.unwrap_or_else(|| self.default_max_radius());
 
         let total_points = self.num_revolutions * self.points_per_revolution;
         let mut spirals = Vec::new();
@@ -113,49 +167,214 @@ impl SpiralGenerator {
             let mut points = Vec::with_capacity(total_points);
             let offset_angle = angular_offset * spiral_idx as f64;
 
-            for i in 0..total_points {
-                let theta = (i as f64 / self.points_per_revolution as f64) * 2.0 * PI
-                    + rotation_offset
-                    + offset_angle;
-                let t = i as f64 / total_points as f64;
-
-                let r = match self.spiral_type {
-                    SpiralType::Archimedean => {
-                        start_radius + (max_radius - start_radius) * t * growth_factor
+            if concentric_connected && self.spiral_type == SpiralType::Concentric {
+                for revolution in 0..self.num_revolutions {
+                    let r = start_radius
+                        + (max_radius - start_radius)
+                            * (revolution as f64 / self.num_revolutions as f64)
+                            * growth_factor;
+                    // Closed ring; the next starts at the same angle
+                    for j in 0..=self.points_per_revolution {
+                        let theta = (j as f64 / self.points_per_revolution as f64) * 2.0 * PI
+                            + rotation_offset
+                            + offset_angle;
+                        points.push((
+                            self.center.0 + r * theta.cos(),
+                            self.center.1 + r * theta.sin(),
+                        ));
                     }
-                    SpiralType::Logarithmic => {
-                        let b = (max_radius / start_radius).ln()
-                            / (self.num_revolutions as f64 * 2.0 * PI);
-                        start_radius * (b * theta * growth_factor).exp()
-                    }
-                    SpiralType::Concentric => {
-                        let revolution = i / self.points_per_revolution;
-                        start_radius
-                            + (max_radius - start_radius)
-                                * (revolution as f64 / self.num_revolutions as f64)
-                                * growth_factor
-                    }
-                };
+                }
+            } else {
+                for i in 0..total_points {
+                    let theta = (i as f64 / self.points_per_revolution as f64) * 2.0 * PI
+                        + rotation_offset
+                        + offset_angle;
+                    let t = i as f64 / total_points as f64;
 
-                let x = self.center.0 + r * theta.cos();
-                let y = self.center.1 + r * theta.sin();
-                points.push((x, y));
+                    let r = match self.spiral_type {
+                        SpiralType::Archimedean => {
+                            start_radius + (max_radius - start_radius) * t * growth_factor
+                        }
+                        SpiralType::Logarithmic => {
+                            let b = (max_radius / start_radius).ln()
+                                / (self.num_revolutions as f64 * 2.0 * PI);
+                            start_radius * (b * theta * growth_factor).exp()
+                        }
+                        SpiralType::Concentric => {
+                            let revolution = i / self.points_per_revolution;
+                            start_radius
+                                + (max_radius - start_radius)
+                                    * (revolution as f64 / self.num_revolutions as f64)
+                                    * growth_factor
+                        }
+                    };
+
+                    let x = self.center.0 + r * theta.cos();
+                    let y = self.center.1 + r * theta.sin();
+                    points.push((x, y));
+                }
+            }
+
+            if clockwise {
+                // Reflect across the line through the center at the start angle
+                let (sin, cos) = (2.0 * (rotation_offset + offset_angle)).sin_cos();
+                let (cx, cy) = self.center;
+                for point in points.iter_mut() {
+                    let (dx, dy) = (point.0 - cx, point.1 - cy);
+                    *point = (cx + dx * cos + dy * sin, cy + dx * sin - dy * cos);
+                }
+            }
+
+            if uniform_arc_length {
+                points = resample_uniform(&points);
             }
 
             spirals.push(points);
         }
 
+        if noise_displacement != 0.0 {
+            let noise = Perlin::new(noise_seed);
+            for points in spirals.iter_mut() {
+                self.displace_radially(points, &noise, noise_displacement, noise_scale);
+            }
+        }
+
+        if inward {
+            for points in spirals.iter_mut() {
+                points.reverse();
+            }
+        }
+
         Ok(spirals)
     }
 
+    /// Generate spiral pattern(s) with a stroke width per point
+    ///
+    /// Returns the same spirals as `generate` with the same options, each
+    /// point as (x, y, width). The width runs linearly from `start_width` at
+    /// the center to `end_width` at the rim by distance along the curve, so
+    /// it tapers evenly however the points are spaced. With `inward` the
+    /// points are reversed but each keeps its width, still thin at the center
+    /// for the default widths.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
+    #[pyo3(signature = (
+        start_width=0.1,
+        end_width=1.0,
+        start_radius=5.0,
+        end_radius=None,
+        rotation_offset=0.0,
+        growth_factor=1.0,
+        num_spirals=1,
+        angular_offset=0.0,
+        uniform_arc_length=false,
+        noise_displacement=0.0,
+        noise_scale=20.0,
+        noise_seed=0,
+        concentric_connected=false,
+        clockwise=false,
+        inward=false
+    ))]
+    fn generate_with_width(
+        &self,
+        start_width: f64,
+        end_width: f64,
+        start_radius: f64,
+        end_radius: Option<f64>,
+        rotation_offset: f64,
+        growth_factor: f64,
+        num_spirals: usize,
+        angular_offset: f64,
+        uniform_arc_length: bool,
+        noise_displacement: f64,
+        noise_scale: f64,
+        noise_seed: u32,
+        concentric_connected: bool,
+        clockwise: bool,
+        inward: bool,
+    ) -> PyResult<Vec<Vec<(f64, f64, f64)>>> {
+        if start_width < 0.0 || end_width < 0.0 {
+            return Err(AxiArtError::InvalidParameter("widths must be non-negative".into()).into());
+        }
+
+        // Generate outward so widths are measured from the center
+        let spirals = self.generate(
+            start_radius,
+            end_radius,
+            rotation_offset,
+            growth_factor,
+            num_spirals,
+            angular_offset,
+            uniform_arc_length,
+            noise_displacement,
+            noise_scale,
+            noise_seed,
+            concentric_connected,
+            clockwise,
+            false,
+        )?;
+
+        Ok(spirals
+            .into_iter()
+            .map(|points| {
+                let mut distance = Vec::with_capacity(points.len());
+                let mut total = 0.0;
+                for (i, &(x, y)) in points.iter().enumerate() {
+                    if i > 0 {
+                        let (px, py) = points[i - 1];
+                        total += (x - px).hypot(y - py);
+                    }
+                    distance.push(total);
+                }
+
+                let mut tapered: Vec<(f64, f64, f64)> = points
+                    .iter()
+                    .zip(distance)
+                    .map(|(&(x, y), d)| {
+                        let t = if total > 0.0 { d / total } else { 0.0 };
+                        (x, y, start_width + (end_width - start_width) * t)
+                    })
+                    .collect();
+                if inward {
+                    tapered.reverse();
+                }
+                tapered
+            })
+            .collect())
+    }
+
     /// Generate circular waves with optional undulation
+    ///
+    /// With `connect`, the circles are joined by a short radial connector
+    /// (each circle starts and ends at angle 0) and returned as a single
+    /// polyline, so the whole pattern plots without a pen lift.
+    ///
+    /// With `spiral_connect`, each circle's radius instead eases into the next
+    /// circle's over the last quarter turn, giving one smooth expanding curve
+    /// with no radial jumps. The easing has zero slope at both ends, so there
+    /// is no visible seam; wave undulation is applied on top.
+    ///
+    /// `num_sides` of 3 or more draws concentric regular polygons instead of
+    /// circles, with `base_radius` as the circumradius and the first vertex
+    /// at angle 0. Points are spaced evenly along the perimeter, so a
+    /// `points_per_circle` that is a multiple of `num_sides` lands exactly on
+    /// every corner; undulation displaces points radially as for circles.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
     #[pyo3(signature = (
         num_circles=20,
         start_radius=10.0,
         end_radius=None,
         points_per_circle=100,
         wave_amplitude=0.0,
-        wave_frequency=5.0
+        wave_frequency=5.0,
+        connect=false,
+        spiral_connect=false,
+        num_sides=0
     ))]
     fn generate_circular_waves(
         &self,
@@ -165,35 +384,64 @@ impl SpiralGenerator {
         points_per_circle: usize,
         wave_amplitude: f64,
         wave_frequency: f64,
+        connect: bool,
+        spiral_connect: bool,
+        num_sides: usize,
     ) -> PyResult<Vec<Vec<(f64, f64)>>> {
-        let max_radius = end_radius.unwrap_or_else(|| {
-            let dx = [self.center.0, self.width - self.center.0];
-            let dy = [self.center.1, self.height - self.center.1];
-            dx.iter()
-                .chain(dy.iter())
-                .fold(f64::INFINITY, |a, &b| a.min(b))
-                * 0.9
-        });
+        if num_sides == 1 || num_sides == 2 {
+            return Err(AxiArtError::InvalidParameter(
+                "num_sides must be 0 (circle) or at least 3".into(),
+            )
+            .into());
+        }
+        let max_radius = end_radius.unwrap_or_else(|| self.default_max_radius());
+        let ring_radius = |idx: usize| {
+            start_radius + (max_radius - start_radius) * (idx as f64 / num_circles as f64)
+        };
+        // Fraction of each turn spent easing into the next circle
+        const BLEND: f64 = 0.25;
 
         let mut circles = Vec::new();
 
         for circle_idx in 0..num_circles {
             let mut points = Vec::with_capacity(points_per_circle + 1);
-            let base_radius = start_radius
-                + (max_radius - start_radius) * (circle_idx as f64 / num_circles as f64);
+            let base_radius = ring_radius(circle_idx);
+            let blend_into = (spiral_connect && circle_idx + 1 < num_circles)
+                .then(|| ring_radius(circle_idx + 1));
+            // The closing point of a blended circle is the next circle's start
+            let last = if blend_into.is_some() {
+                points_per_circle.saturating_sub(1)
+            } else {
+                points_per_circle
+            };
 
-            for i in 0..=points_per_circle {
-                let theta = (i as f64 / points_per_circle as f64) * 2.0 * PI;
-                let r = base_radius + wave_amplitude * (wave_frequency * theta).sin();
+            for i in 0..=last {
+                let t = i as f64 / points_per_circle as f64;
+                let theta = t * 2.0 * PI;
+                let mut base = base_radius;
+                if let Some(next_radius) = blend_into {
+                    let u = ((t - (1.0 - BLEND)) / BLEND).max(0.0);
+                    base += (next_radius - base_radius) * u * u * (3.0 - 2.0 * u);
+                }
+                let (dx, dy) = ring_point(num_sides, t);
+                let scale = dx.hypot(dy);
+                let r = base * scale + wave_amplitude * (wave_frequency * theta).sin();
 
-                let x = self.center.0 + r * theta.cos();
-                let y = self.center.1 + r * theta.sin();
+                let x = self.center.0 + r * dx / scale;
+                let y = self.center.1 + r * dy / scale;
                 points.push((x, y));
             }
 
             circles.push(points);
         }
 
+        if connect || spiral_connect {
+            // Consecutive circles start at the same angle, so chaining them
+            // inserts the radial connector between end and next start (or
+            // continues the blended curve seamlessly)
+            return Ok(vec![circles.concat()]);
+        }
+
         Ok(circles)
     }
 
@@ -234,4 +482,386 @@ impl SpiralGenerator {
 
         Ok(vec![points])
     }
+
+    /// Generate phyllotaxis (Vogel spiral) points
+    ///
+    /// Places points by the sunflower formula `r = spacing * sqrt(n)`,
+    /// `theta = n * angle`, producing the classic seed-head dot distribution.
+    ///
+    /// # Arguments
+    /// * `num_points` - Number of points to place
+    /// * `spacing` - Radial spacing multiplier
+    /// * `angle` - Divergence angle in degrees (137.5 is the golden angle)
+    ///
+    /// # Returns
+    /// Points as Vec<(f64, f64)>, centered on the generator center
+    #[pyo3(signature = (num_points=1000, spacing=2.0, angle=137.5))]
+    fn generate_phyllotaxis(
+        &self,
+        num_points: usize,
+        spacing: f64,
+        angle: f64,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        let divergence = angle.to_radians();
+        let mut points = Vec::with_capacity(num_points);
+
+        for n in 0..num_points {
+            let theta = n as f64 * divergence;
+            let r = spacing * (n as f64).sqrt();
+
+            let x = self.center.0 + r * theta.cos();
+            let y = self.center.1 + r * theta.sin();
+
+            // Only include points within canvas bounds
+            if x >= 0.0 && x <= self.width && y >= 0.0 && y <= self.height {
+                points.push((x, y));
+            }
+        }
+
+        Ok(points)
+    }
+
+    /// Generate a rose curve (rhodonea)
+    ///
+    /// Traces `r = amplitude * cos(k * theta)` as a single closed polyline.
+    /// Rational `k = n/d` is supported: theta is swept over enough full turns
+    /// (d or 2d half-turns depending on parity) for the petals to close.
+    ///
+    /// # Arguments
+    /// * `k` - Petal frequency (integer or rational)
+    /// * `amplitude` - Petal length (uses max radius if None)
+    /// * `points` - Number of samples along the curve (must be positive)
+    #[pyo3(signature = (k=4.0, amplitude=None, points=1000))]
+    fn generate_rose(
+        &self,
+        k: f64,
+        amplitude: Option<f64>,
+        points: usize,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if points == 0 {
+            return Err(AxiArtError::InvalidParameter("points must be positive".into()).into());
+        }
+
+        let amplitude = amplitude.unwrap_or_else(|| self.default_max_radius());
+        let (n, d) = rational_approximation(k, 1000);
+
+        // r(theta + pi*d) = (-1)^n r(theta); the curve closes after pi*d when
+        // that sign flip coincides with the point reflection, i.e. n*d odd
+        let sweep = if (n * d) % 2 == 1 {
+            PI * d as f64
+        } else {
+            2.0 * PI * d as f64
+        };
+
+        let mut curve = Vec::with_capacity(points + 1);
+        for i in 0..=points {
+            let theta = sweep * i as f64 / points as f64;
+            let r = amplitude * (k * theta).cos();
+
+            let x = self.center.0 + r * theta.cos();
+            let y = self.center.1 + r * theta.sin();
+            curve.push((x, y));
+        }
+
+        Ok(vec![curve])
+    }
+
+    /// Generate a spirograph curve (hypotrochoid)
+    ///
+    /// A circle of radius `inner_radius` rolls inside a fixed circle of radius
+    /// `outer_radius`, tracing the pen at `pen_distance` from its center:
+    ///
+    /// x = (R - r) cos t + d cos((R - r) / r * t)
+    /// y = (R - r) sin t - d sin((R - r) / r * t)
+    ///
+    /// The parameter is swept over r / gcd(R, r) turns so the loops close,
+    /// returning one continuous polyline.
+    ///
+    /// # Arguments
+    /// * `outer_radius` - Radius R of the fixed circle
+    /// * `inner_radius` - Radius r of the rolling circle
+    /// * `pen_distance` - Distance d of the pen from the rolling circle's center
+    /// * `points` - Number of samples along the curve (must be positive)
+    #[pyo3(signature = (outer_radius=80.0, inner_radius=30.0, pen_distance=50.0, points=5000))]
+    fn generate_hypotrochoid(
+        &self,
+        outer_radius: f64,
+        inner_radius: f64,
+        pen_distance: f64,
+        points: usize,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if inner_radius <= 0.0 {
+            return Err(
+                AxiArtError::InvalidParameter("inner_radius must be positive".into()).into(),
+            );
+        }
+        if points == 0 {
+            return Err(AxiArtError::InvalidParameter("points must be positive".into()).into());
+        }
+
+        // R / r = n / m in lowest terms closes after m turns
+        let (_, turns) = rational_approximation(outer_radius / inner_radius, 1000);
+        let sweep = 2.0 * PI * turns as f64;
+        let diff = outer_radius - inner_radius;
+        let ratio = diff / inner_radius;
+
+        let mut curve = Vec::with_capacity(points + 1);
+        for i in 0..=points {
+            let t = sweep * i as f64 / points as f64;
+
+            let x = self.center.0 + diff * t.cos() + pen_distance * (ratio * t).cos();
+            let y = self.center.1 + diff * t.sin() - pen_distance * (ratio * t).sin();
+            curve.push((x, y));
+        }
+
+        Ok(vec![curve])
+    }
+
+    /// Generate an Euler spiral (clothoid)
+    ///
+    /// A curve whose curvature grows linearly with arc length s,
+    /// `curvature = curvature_rate * s`, so its heading is
+    /// `curvature_rate * s² / 2`. Integrating the Fresnel-style
+    /// `dx = cos(heading)`, `dy = sin(heading)` traces the classic transition
+    /// curve: nearly straight at the start, then winding ever tighter around
+    /// its limit point. The curve starts at the generator center heading
+    /// along +x (angle 0).
+    ///
+    /// # Arguments
+    /// * `length` - Arc length of the curve
+    /// * `curvature_rate` - Curvature gained per unit of arc length; negative
+    ///   values wind the other way
+    /// * `points` - Number of integration steps along the curve
+    #[pyo3(signature = (length=200.0, curvature_rate=0.001, points=1000))]
+    fn generate_clothoid(
+        &self,
+        length: f64,
+        curvature_rate: f64,
+        points: usize,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if points == 0 {
+            return Err(AxiArtError::InvalidParameter("points must be positive".into()).into());
+        }
+
+        let ds = length / points as f64;
+        let (mut x, mut y) = self.center;
+        let mut curve = Vec::with_capacity(points + 1);
+        curve.push((x, y));
+        for i in 0..points {
+            // Midpoint heading keeps the integration second-order accurate
+            let s = (i as f64 + 0.5) * ds;
+            let heading = curvature_rate * s * s / 2.0;
+            x += ds * heading.cos();
+            y += ds * heading.sin();
+            curve.push((x, y));
+        }
+
+        Ok(vec![curve])
+    }
+
+    /// Fill a polygon with a single spiraling stroke
+    ///
+    /// Grows an Archimedean spiral with ring spacing `spacing` from the
+    /// polygon centroid until it covers the farthest vertex, then clips it to
+    /// the polygon. The stroke is split wherever it leaves and re-enters the
+    /// shape, so concave polygons yield several polylines.
+    ///
+    /// # Arguments
+    /// * `polygon` - Closed boundary as a list of (x, y) vertices
+    /// * `spacing` - Distance between successive spiral rings
+    #[pyo3(signature = (polygon, spacing=2.0))]
+    fn generate_spiral_fill(
+        &self,
+        polygon: Vec<(f64, f64)>,
+        spacing: f64,
+    ) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        if polygon.len() < 3 {
+            return Err(
+                AxiArtError::InvalidParameter("Polygon needs at least 3 vertices".into()).into(),
+            );
+        }
+        if spacing <= 0.0 {
+            return Err(AxiArtError::InvalidParameter("spacing must be positive".into()).into());
+        }
+
+        let (cx, cy) = polygon_centroid(&polygon);
+        let max_radius = polygon
+            .iter()
+            .map(|&(x, y)| ((x - cx).powi(2) + (y - cy).powi(2)).sqrt())
+            .fold(0.0, f64::max);
+
+        // r = spacing * theta / 2pi; step theta so points stay ~spacing/4 apart
+        let growth = spacing / (2.0 * PI);
+        let mut spiral = Vec::new();
+        let mut theta: f64 = 0.0;
+        loop {
+            let r = growth * theta;
+            spiral.push((cx + r * theta.cos(), cy + r * theta.sin()));
+            if r > max_radius {
+                break;
+            }
+            theta += (0.25 * spacing / r.max(spacing)).min(0.2);
+        }
+
+        Ok(clip_polyline_to_polygon(&spiral, &polygon, true))
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
+}
+
+impl SpiralGenerator {
+    /// Offset points along their outward normal (away from the center) by noise
+    fn displace_radially(
+        &self,
+        points: &mut [(f64, f64)],
+        noise: &Perlin,
+        amplitude: f64,
+        scale: f64,
+    ) {
+        for p in points.iter_mut() {
+            let dx = p.0 - self.center.0;
+            let dy = p.1 - self.center.1;
+            let dist = (dx * dx + dy * dy).sqrt();
+            if dist < 1e-9 {
+                continue;
+            }
+
+            let offset = amplitude * noise.get([p.0 / scale, p.1 / scale]);
+            p.0 += dx / dist * offset;
+            p.1 += dy / dist * offset;
+        }
+    }
+
+    /// Default outer radius: 90% of the distance to the nearest canvas edge
+    fn default_max_radius(&self) -> f64 {
+        let dx = [self.center.0, self.width - self.center.0];
+        let dy = [self.center.1, self.height - self.center.1];
+        dx.iter()
+            .chain(dy.iter())
+            .fold(f64::INFINITY, |a, &b| a.min(b))
+            * 0.9
+    }
+}
+
+/// Point at perimeter fraction `t` of a unit ring centered on the origin
+///
+/// A circle for `num_sides` 0, otherwise the regular polygon inscribed in
+/// the unit circle with its first vertex at angle 0, traversed at constant
+/// speed along its edges.
+fn ring_point(num_sides: usize, t: f64) -> (f64, f64) {
+    if num_sides == 0 {
+        let theta = t * 2.0 * PI;
+        return (theta.cos(), theta.sin());
+    }
+
+    let n = num_sides as f64;
+    let edge = (t * n).floor().min(n - 1.0);
+    let s = t * n - edge;
+    let (a0, a1) = (edge * 2.0 * PI / n, (edge + 1.0) * 2.0 * PI / n);
+    (
+        a0.cos() + (a1.cos() - a0.cos()) * s,
+        a0.sin() + (a1.sin() - a0.sin()) * s,
+    )
+}
+
+/// Resample a polyline so consecutive points are equally spaced along it
+///
+/// Keeps the original point count and endpoints, interpolating linearly
+/// along the cumulative arc length.
+fn resample_uniform(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+
+    let mut cumulative = Vec::with_capacity(n);
+    cumulative.push(0.0);
+    for w in points.windows(2) {
+        let (dx, dy) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+        cumulative.push(cumulative.last().unwrap() + (dx * dx + dy * dy).sqrt());
+    }
+
+    let total = cumulative[n - 1];
+    if total == 0.0 {
+        return points.to_vec();
+    }
+
+    let mut resampled = Vec::with_capacity(n);
+    let mut seg = 0;
+    for i in 0..n {
+        let target = total * i as f64 / (n - 1) as f64;
+        while seg < n - 2 && cumulative[seg + 1] < target {
+            seg += 1;
+        }
+
+        let seg_len = cumulative[seg + 1] - cumulative[seg];
+        let t = if seg_len > 0.0 {
+            ((target - cumulative[seg]) / seg_len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (x0, y0) = points[seg];
+        let (x1, y1) = points[seg + 1];
+        resampled.push((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t));
+    }
+
+    resampled
+}
+
+/// Approximate a positive ratio as a reduced fraction n/d via continued fractions
+///
+/// Stops once the denominator would exceed `max_denominator` or the
+/// approximation is exact to floating precision.
+fn rational_approximation(value: f64, max_denominator: u64) -> (u64, u64) {
+    let value = value.abs();
+    let (mut h0, mut h1) = (0_u64, 1_u64);
+    let (mut k0, mut k1) = (1_u64, 0_u64);
+    let mut x = value;
+
+    loop {
+        let a = x.floor();
+        let a_int = a as u64;
+        let h2 = a_int.saturating_mul(h1).saturating_add(h0);
+        let k2 = a_int.saturating_mul(k1).saturating_add(k0);
+        if k2 > max_denominator {
+            break;
+        }
+        (h0, h1, k0, k1) = (h1, h2, k1, k2);
+
+        let frac = x - a;
+        if frac < 1e-9 || (h1 as f64 / k1 as f64 - value).abs() < 1e-9 {
+            break;
+        }
+        x = 1.0 / frac;
+    }
+
+    (h1, k1.max(1))
+}
+
+impl Generator for SpiralGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        self.generate(
+            5.0, None, 0.0, 1.0, 1, 0.0, false, 0.0, 20.0, 0, false, false, false,
+        )
+        .map(Paths)
+    }
+
+    /// The square around the default spiral's outer radius, which every
+    /// point stays within
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        let r = self.default_max_radius();
+        let (cx, cy) = self.center;
+        Ok(Some((cx - r, cy - r, cx + r, cy + r)))
+    }
 }
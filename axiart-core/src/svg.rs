@@ -0,0 +1,107 @@
+//! SVG export for generator output
+//!
+//! Turns polylines from any generator into a standalone SVG document in
+//! millimeters, ready for plotting software such as the AxiDraw extension.
+
+use crate::error::AxiArtError;
+use crate::paths::Paths;
+use pyo3::prelude::*;
+use std::fmt::Write;
+
+/// Render polylines as an SVG document
+///
+/// Each path becomes one `<polyline>` element. Coordinates are canvas units
+/// (millimeters by default), with the document sized `width` × `height` mm
+/// and a matching viewBox.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `width` - Document width
+/// * `height` - Document height
+/// * `stroke_width` - Stroke width in canvas units
+/// * `colors` - Optional stroke color per path (any SVG color); black if None
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import SpiralGenerator, to_svg
+///
+/// spiral = SpiralGenerator(297.0, 210.0)
+/// svg = to_svg(spiral.generate(), 297.0, 210.0)
+/// open("spiral.svg", "w").write(svg)
+/// ```
+#[pyfunction]
+#[pyo3(signature = (paths, width=297.0, height=210.0, stroke_width=0.5, colors=None))]
+pub fn to_svg(
+    paths: Paths,
+    width: f64,
+    height: f64,
+    stroke_width: f64,
+    colors: Option<Vec<String>>,
+) -> PyResult<String> {
+    if let Some(colors) = &colors {
+        if colors.len() != paths.0.len() {
+            return Err(AxiArtError::InvalidParameter(
+                "colors must have one entry per path".into(),
+            )
+            .into());
+        }
+    }
+
+    let mut svg = String::new();
+    // Writing to a String cannot fail
+    let _ = writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">"#,
+        w = format_number(width),
+        h = format_number(height),
+    );
+    let _ = writeln!(
+        svg,
+        r#"<g fill="none" stroke="black" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round">"#,
+        format_number(stroke_width),
+    );
+
+    for (idx, path) in paths.0.iter().enumerate() {
+        if path.is_empty() {
+            continue;
+        }
+
+        svg.push_str("<polyline points=\"");
+        for (i, &(x, y)) in path.iter().enumerate() {
+            if i > 0 {
+                svg.push(' ');
+            }
+            let _ = write!(svg, "{},{}", format_number(x), format_number(y));
+        }
+        svg.push('"');
+
+        if let Some(colors) = &colors {
+            let _ = write!(svg, " stroke=\"{}\"", escape_attribute(&colors[idx]));
+        }
+        svg.push_str("/>\n");
+    }
+
+    svg.push_str("</g>\n</svg>\n");
+    Ok(svg)
+}
+
+/// Format a coordinate with at most three decimals (1 µm at mm scale)
+pub(crate) fn format_number(value: f64) -> String {
+    let formatted = format!("{value:.3}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+/// Escape a string for use inside a double-quoted XML attribute
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,59 @@
+//! Process-wide thread count
+//!
+//! Parallel work runs on rayon's global pool, which uses every core, unless
+//! `set_num_threads` has installed a smaller pool. Each parallel section runs
+//! through `install` so that it picks up whichever pool is current.
+
+use crate::error::AxiArtError;
+use pyo3::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::{Arc, RwLock};
+
+/// Pool set by `set_num_threads`, or None for rayon's global pool
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+/// Limit how many threads the parallel generators use
+///
+/// By default parallel work (streamlines, stippling, Voronoi sampling, path
+/// transforms, ...) spreads over every core. After this call it runs on a
+/// dedicated pool of `n` threads instead, leaving the rest of the machine to
+/// other work. Calls already in progress finish on the pool they started on.
+/// Pass None to return to using every core.
+///
+/// # Examples
+///
+/// ```python
+/// from axiart_core import set_num_threads
+///
+/// set_num_threads(2)  # e.g. inside a shared web server
+/// ```
+#[pyfunction]
+#[pyo3(signature = (n))]
+pub fn set_num_threads(n: Option<usize>) -> PyResult<()> {
+    let pool = match n {
+        Some(0) => {
+            return Err(AxiArtError::InvalidParameter("n must be at least 1".into()).into());
+        }
+        Some(n) => Some(Arc::new(
+            ThreadPoolBuilder::new()
+                .num_threads(n)
+                .thread_name(|i| format!("axiart-{i}"))
+                .build()
+                .map_err(|e| AxiArtError::InvalidParameter(e.to_string()))?,
+        )),
+        None => None,
+    };
+    *POOL.write().unwrap_or_else(|e| e.into_inner()) = pool;
+    Ok(())
+}
+
+/// Run `op` on the pool set by `set_num_threads`, so the rayon iterators
+/// inside it use that many threads; without one, `op` runs as is
+pub(crate) fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    // Clone the handle so the lock isn't held while the work runs
+    let pool = POOL.read().unwrap_or_else(|e| e.into_inner()).clone();
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
@@ -0,0 +1,232 @@
+//! Affine transforms for path sets
+//!
+//! Translate, rotate, scale and mirror generator output to place several
+//! patterns on one page. Every transform is an affine matrix applied to all
+//! points in parallel. `bounding_box` measures a path set to plan the layout
+//! and `fit_to_page` scales one to fill the page.
+
+use crate::error::AxiArtError;
+use crate::paths::{Bounds, Paths};
+use crate::threads;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+/// Affine matrix [[a, b, tx], [c, d, ty]] mapping (x, y) to
+/// (a x + b y + tx, c x + d y + ty)
+type Matrix = [[f64; 3]; 2];
+
+/// Shift paths by (dx, dy)
+#[pyfunction]
+pub fn translate(paths: Paths, dx: f64, dy: f64) -> Paths {
+    affine(paths, [[1.0, 0.0, dx], [0.0, 1.0, dy]])
+}
+
+/// Rotate paths about a point
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `angle` - Rotation in degrees; positive turns clockwise on the page,
+///   since the canvas y axis points down
+/// * `center` - Pivot point (defaults to the origin)
+#[pyfunction]
+#[pyo3(signature = (paths, angle, center=(0.0, 0.0)))]
+pub fn rotate(paths: Paths, angle: f64, center: (f64, f64)) -> Paths {
+    affine(paths, rotation(angle, center))
+}
+
+/// Scale paths about a point
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `sx` - Horizontal scale factor
+/// * `sy` - Vertical scale factor (defaults to `sx` for uniform scaling)
+/// * `center` - Fixed point of the scaling (defaults to the origin)
+#[pyfunction]
+#[pyo3(signature = (paths, sx, sy=None, center=(0.0, 0.0)))]
+pub fn scale(paths: Paths, sx: f64, sy: Option<f64>, center: (f64, f64)) -> Paths {
+    let sy = sy.unwrap_or(sx);
+    let (cx, cy) = center;
+    affine(paths, [[sx, 0.0, cx - sx * cx], [0.0, sy, cy - sy * cy]])
+}
+
+/// Mirror paths across a vertical or horizontal line
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `axis` - "x" negates x coordinates (left-right flip across the line
+///   x = `position`); "y" negates y (top-bottom flip across y = `position`)
+/// * `position` - Coordinate of the mirror line, e.g. width / 2 to flip a
+///   pattern in place on the page
+#[pyfunction]
+#[pyo3(signature = (paths, axis="x", position=0.0))]
+pub fn mirror(paths: Paths, axis: &str, position: f64) -> PyResult<Paths> {
+    let matrix = match axis.to_lowercase().as_str() {
+        "x" => [[-1.0, 0.0, 2.0 * position], [0.0, 1.0, 0.0]],
+        "y" => [[1.0, 0.0, 0.0], [0.0, -1.0, 2.0 * position]],
+        _ => {
+            return Err(
+                AxiArtError::InvalidOption("Invalid mirror axis. Use 'x' or 'y'".into()).into(),
+            )
+        }
+    };
+    Ok(affine(paths, matrix))
+}
+
+/// Repeat paths around a point for rose-window and kaleidoscope layouts
+///
+/// Copy k is the whole path set rotated by k * 360 / n degrees about
+/// `center`, so the result has n-fold rotational symmetry.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `n` - Number of copies, including the original (must be at least 1)
+/// * `center` - Center of symmetry (defaults to the origin)
+/// * `mirror` - Also add each copy reflected across its own horizontal axis
+///   through `center`, giving 2n copies with mirror symmetry as well
+///
+/// # Returns
+/// The combined path set, copy by copy, each mirrored copy right after its
+/// rotated one
+#[pyfunction]
+#[pyo3(signature = (paths, n, center=(0.0, 0.0), mirror=false))]
+pub fn radial_symmetry(
+    paths: Paths,
+    n: usize,
+    center: (f64, f64),
+    mirror: bool,
+) -> PyResult<Paths> {
+    if n == 0 {
+        return Err(AxiArtError::InvalidParameter("n must be at least 1".into()).into());
+    }
+
+    let reflection = [[1.0, 0.0, 0.0], [0.0, -1.0, 2.0 * center.1]];
+    let copies = if mirror { 2 * n } else { n };
+    let mut combined = Vec::with_capacity(paths.0.len() * copies);
+    for k in 0..n {
+        let turn = rotation(360.0 * k as f64 / n as f64, center);
+        combined.extend(affine(paths.clone(), turn).0);
+        if mirror {
+            combined.extend(affine(paths.clone(), compose(turn, reflection)).0);
+        }
+    }
+    Ok(Paths(combined))
+}
+
+/// Apply an arbitrary affine matrix to paths
+///
+/// `matrix` is [[a, b, tx], [c, d, ty]] and maps each point (x, y) to
+/// (a x + b y + tx, c x + d y + ty), the same layout as the first two rows of
+/// a 3×3 homogeneous transform, so composed transforms can be applied in one
+/// pass.
+#[pyfunction]
+pub fn apply_matrix(matrix: Matrix, paths: Paths) -> Paths {
+    affine(paths, matrix)
+}
+
+/// Extent of a path set as (min_x, min_y, max_x, max_y)
+///
+/// Returns None when there are no points.
+#[pyfunction]
+pub fn bounding_box(paths: Paths) -> Option<Bounds> {
+    paths.bounds()
+}
+
+/// Scale and center paths to fill a page
+///
+/// Uniformly scales the paths' bounding box to the largest size that fits
+/// inside the page minus `margin` on every side, then centers it. Aspect ratio
+/// is preserved; a path set with no extent (a single point) is only centered.
+///
+/// # Arguments
+/// * `paths` - Polylines as lists of (x, y) points
+/// * `page_width`, `page_height` - Target page size (A4 landscape by default)
+/// * `margin` - Blank border kept on each side
+/// * `return_transform` - Also return the applied matrix
+///
+/// # Returns
+/// The fitted paths, or (paths, matrix) with `return_transform`, where matrix
+/// is in the `apply_matrix` layout so it can be reused on related layers.
+#[pyfunction]
+#[pyo3(signature = (paths, page_width=297.0, page_height=210.0, margin=10.0, return_transform=false))]
+pub fn fit_to_page(
+    py: Python<'_>,
+    paths: Paths,
+    page_width: f64,
+    page_height: f64,
+    margin: f64,
+    return_transform: bool,
+) -> PyResult<PyObject> {
+    let (avail_w, avail_h) = (page_width - 2.0 * margin, page_height - 2.0 * margin);
+    if avail_w <= 0.0 || avail_h <= 0.0 {
+        return Err(
+            AxiArtError::InvalidParameter("margin leaves no room on the page".into()).into(),
+        );
+    }
+
+    let matrix = match paths.bounds() {
+        None => [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        Some((x0, y0, x1, y1)) => {
+            let (w, h) = (x1 - x0, y1 - y0);
+            let s = match (w > 0.0, h > 0.0) {
+                (true, true) => (avail_w / w).min(avail_h / h),
+                (true, false) => avail_w / w,
+                (false, true) => avail_h / h,
+                (false, false) => 1.0,
+            };
+            // Map the box center onto the page center
+            let (cx, cy) = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+            [
+                [s, 0.0, page_width / 2.0 - s * cx],
+                [0.0, s, page_height / 2.0 - s * cy],
+            ]
+        }
+    };
+
+    let fitted = affine(paths, matrix);
+    if return_transform {
+        Ok((fitted, matrix).into_py(py))
+    } else {
+        Ok(fitted.into_py(py))
+    }
+}
+
+/// Rotation by `angle` degrees about `center`
+fn rotation(angle: f64, center: (f64, f64)) -> Matrix {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let (cx, cy) = center;
+    [
+        [cos, -sin, cx - cos * cx + sin * cy],
+        [sin, cos, cy - sin * cx - cos * cy],
+    ]
+}
+
+/// The matrix applying `b` first, then `a`
+fn compose(a: Matrix, b: Matrix) -> Matrix {
+    let mut m = [[0.0; 3]; 2];
+    for (row, a_row) in m.iter_mut().zip(&a) {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a_row[0] * b[0][j] + a_row[1] * b[1][j];
+        }
+        row[2] += a_row[2];
+    }
+    m
+}
+
+fn affine(paths: Paths, m: Matrix) -> Paths {
+    Paths(threads::install(|| {
+        paths
+            .0
+            .into_par_iter()
+            .map(|path| {
+                path.into_iter()
+                    .map(|(x, y)| {
+                        (
+                            m[0][0] * x + m[0][1] * y + m[0][2],
+                            m[1][0] * x + m[1][1] * y + m[1][2],
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }))
+}
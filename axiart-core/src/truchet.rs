@@ -1,13 +1,29 @@
 //! High-performance Truchet Tiles generator
 //!
 //! Generates geometric patterns using rotated tiles arranged on a grid.
-//! Supports various tile types including diagonal lines, arcs, and multi-arc patterns.
+//! Supports various tile types including diagonal lines, arcs, multi-arc and circle patterns.
 
+use crate::error::AxiArtError;
+use crate::geometry::{clip_polyline_to_rect, Rect};
+use crate::paths::{Bounds, Generator, Paths};
+use crate::seed::resolve_seed;
+use noise::{NoiseFn, Perlin};
 use pyo3::prelude::*;
+use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
 use std::f64::consts::PI;
 
+/// Straight stroke as ((x1, y1), (x2, y2))
+type Segment = ((f64, f64), (f64, f64));
+
+/// Curved stroke as a list of points
+type Polyline = Vec<(f64, f64)>;
+
+/// One tile's connectivity as (col, row, pairs of joined boundary ports)
+type TileConnections = (usize, usize, Vec<(usize, usize)>);
+
 /// Tile type for Truchet pattern
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[pyclass(eq, eq_int)]
@@ -17,6 +33,7 @@ pub enum TileType {
     DoubleArc,
     Triangle,
     Maze,
+    Circle,
 }
 
 #[pymethods]
@@ -29,9 +46,8 @@ impl TileType {
             "double_arc" | "doublearc" => Ok(TileType::DoubleArc),
             "triangle" => Ok(TileType::Triangle),
             "maze" => Ok(TileType::Maze),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(
-                "Invalid tile type. Use 'diagonal', 'arc', 'double_arc', 'triangle', or 'maze'",
-            )),
+            "circle" => Ok(TileType::Circle),
+            _ => Err(AxiArtError::InvalidOption("Invalid tile type. Use 'diagonal', 'arc', 'double_arc', 'triangle', 'maze', or 'circle'".into()).into()),
         }
     }
 }
@@ -62,15 +78,32 @@ pub struct TruchetGenerator {
     height: f64,
     tile_type: TileType,
     grid_size: usize,
-    tile_size: f64,
+    cols: usize,
+    rows: usize,
+    tile_width: f64,
+    tile_height: f64,
     randomness: f64,
     arc_segments: usize,
+    arc_width: f64,
+    noise_scale: Option<f64>,
+    noise: Perlin,
+    rotation_weights: Option<WeightedIndex<f64>>,
+    free_rotation: bool,
+    /// Seed the RNG started from, drawn from entropy when none was given
+    seed: u64,
+    /// Whether each layout restarts the RNG from `seed` (a seed was given
+    /// or came from the global stream) rather than continuing it
+    reseed: bool,
     rng: ChaCha8Rng,
 }
 
 #[pymethods]
 impl TruchetGenerator {
     #[new]
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
     #[pyo3(signature = (
         width=297.0,
         height=210.0,
@@ -78,7 +111,14 @@ impl TruchetGenerator {
         grid_size=20,
         randomness=0.5,
         arc_segments=16,
-        seed=None
+        seed=None,
+        noise_scale=None,
+        noise_seed=0,
+        grid_cols=None,
+        grid_rows=None,
+        rotation_weights=None,
+        free_rotation=false,
+        arc_width=0.0
     ))]
     fn new(
         width: f64,
@@ -88,24 +128,85 @@ impl TruchetGenerator {
         randomness: f64,
         arc_segments: usize,
         seed: Option<u64>,
+        noise_scale: Option<f64>,
+        noise_seed: u32,
+        grid_cols: Option<usize>,
+        grid_rows: Option<usize>,
+        rotation_weights: Option<[f64; 4]>,
+        free_rotation: bool,
+        arc_width: f64,
     ) -> PyResult<Self> {
+        if grid_size == 0 || grid_cols == Some(0) || grid_rows == Some(0) {
+            return Err(AxiArtError::InvalidParameter(
+                "grid_size, grid_cols and grid_rows must be positive".into(),
+            )
+            .into());
+        }
+        if noise_scale.is_some_and(|scale| scale <= 0.0) {
+            return Err(
+                AxiArtError::InvalidParameter("noise_scale must be positive".into()).into(),
+            );
+        }
+        let rotation_weights = rotation_weights
+            .map(|weights| {
+                WeightedIndex::new(weights).map_err(|_| {
+                    AxiArtError::InvalidParameter(
+                        "rotation_weights must be non-negative with a positive sum".into(),
+                    )
+                })
+            })
+            .transpose()?;
         let tile_type_enum = TileType::from_str(tile_type)?;
-        let tile_size = width.min(height) / grid_size as f64;
-
-        let rng = if let Some(s) = seed {
-            ChaCha8Rng::seed_from_u64(s)
+        // Square tiles from grid_size by default (the last row/column may run
+        // past the edge); explicit cols/rows stretch tiles to fill the canvas,
+        // with a missing count chosen to keep tiles as square as possible
+        let (cols, rows, tile_width, tile_height) = if grid_cols.is_none() && grid_rows.is_none() {
+            let tile_size = width.min(height) / grid_size as f64;
+            (
+                (width / tile_size).ceil() as usize,
+                (height / tile_size).ceil() as usize,
+                tile_size,
+                tile_size,
+            )
         } else {
-            ChaCha8Rng::from_entropy()
+            let cols = grid_cols.unwrap_or_else(|| {
+                let rows = grid_rows.unwrap_or(1) as f64;
+                ((width * rows / height).round() as usize).max(1)
+            });
+            let rows = grid_rows
+                .unwrap_or_else(|| ((height * cols as f64 / width).round() as usize).max(1));
+            (cols, rows, width / cols as f64, height / rows as f64)
         };
+        if !(0.0..tile_width.min(tile_height)).contains(&arc_width) {
+            return Err(AxiArtError::InvalidParameter(
+                "arc_width must be non-negative and narrower than a tile".into(),
+            )
+            .into());
+        }
+
+        let resolved = resolve_seed(seed);
+        // Draw a seed from entropy when none is given, so it can be reported
+        let seed = resolved.unwrap_or_else(|| rand::thread_rng().gen());
+        let rng = ChaCha8Rng::seed_from_u64(seed);
 
         Ok(TruchetGenerator {
             width,
             height,
             tile_type: tile_type_enum,
             grid_size,
-            tile_size,
+            cols,
+            rows,
+            tile_width,
+            tile_height,
             randomness: randomness.clamp(0.0, 1.0),
             arc_segments,
+            arc_width,
+            noise_scale,
+            noise: Perlin::new(noise_seed),
+            rotation_weights,
+            free_rotation,
+            seed,
+            reseed: resolved.is_some(),
             rng,
         })
     }
@@ -118,47 +219,137 @@ impl TruchetGenerator {
     ///
     /// For arc-based tiles, curves will contain the arc polylines.
     /// For diagonal tiles, lines will contain the diagonal segments.
-    fn generate(&mut self) -> PyResult<(Vec<((f64, f64), (f64, f64))>, Vec<Vec<(f64, f64)>>)> {
+    ///
+    /// With a `seed`, every call returns the same pattern, and generators
+    /// built with the same parameters and seed produce identical output.
+    fn generate(&mut self) -> PyResult<(Vec<Segment>, Vec<Polyline>)> {
+        Ok(self.generate_strokes())
+    }
+
+    /// Generate the pattern along with per-tile connectivity
+    ///
+    /// Returns (lines, curves, connectivity). `lines` and `curves` are exactly
+    /// what `generate` returns for the same seed. `connectivity` has one entry
+    /// per tile, in row-major order, as (col, row, connections), where each
+    /// connection is a pair of boundary ports joined by one of the tile's
+    /// strokes. Ports are numbered clockwise from the top-left corner:
+    ///
+    /// ```text
+    /// 0 --- 1 --- 2
+    /// |           |
+    /// 7           3
+    /// |           |
+    /// 6 --- 5 --- 4
+    /// ```
+    ///
+    /// Even ports are corners, odd ports are edge midpoints. A port on a shared
+    /// boundary is the same point as the matching port of the neighbor tile
+    /// (e.g. port 3 meets the right neighbor's port 7), so loops and connected
+    /// regions can be traced across the grid. Arc and double-arc tiles join
+    /// corners 0 and 4; maze tiles join two edge midpoints through the center;
+    /// circle tiles link all four edge midpoints in a ring. With
+    /// `free_rotation` the ports describe each tile before its extra rotation.
+    fn generate_with_connectivity(
+        &mut self,
+    ) -> PyResult<(Vec<Segment>, Vec<Polyline>, Vec<TileConnections>)> {
         let mut lines = Vec::new();
         let mut curves = Vec::new();
+        let mut connectivity = Vec::new();
 
-        let cols = (self.width / self.tile_size).ceil() as usize;
-        let rows = (self.height / self.tile_size).ceil() as usize;
+        for (col, row, rotation, angle) in self.tile_layout() {
+            self.generate_tile(col, row, rotation, angle, &mut lines, &mut curves);
+            connectivity.push((col, row, self.tile_connections(rotation)));
+        }
 
-        for row in 0..rows {
-            for col in 0..cols {
-                let x = col as f64 * self.tile_size;
-                let y = row as f64 * self.tile_size;
+        Ok((lines, curves, connectivity))
+    }
 
-                // Determine rotation (0, 1, 2, 3 for 0°, 90°, 180°, 270°)
-                let rotation = if self.rng.gen::<f64>() < self.randomness {
-                    self.rng.gen_range(0..4)
-                } else {
-                    // Use pattern based on position
-                    (col + row) % 2
-                };
+    /// Generate the pattern as continuous paths
+    ///
+    /// Builds the same tiles as `generate`, then follows each stroke across
+    /// tile boundaries, stitching strokes that meet end to end into single
+    /// polylines. Where exactly two strokes meet they are joined; junctions
+    /// where more strokes meet end a path. Paths that come back to their start
+    /// are returned as closed loops (first point repeated at the end).
+    ///
+    /// Returns a list of polylines covering both the line and curve strokes.
+    /// Ribbon arcs (`arc_width`) end off the tile lattice and are returned
+    /// unjoined.
+    fn generate_joined(&mut self) -> PyResult<Vec<Polyline>> {
+        let (lines, curves) = self.generate_strokes();
 
-                match self.tile_type {
-                    TileType::Diagonal => {
-                        self.generate_diagonal_tile(x, y, rotation, &mut lines);
-                    }
-                    TileType::Arc => {
-                        self.generate_arc_tile(x, y, rotation, &mut curves);
-                    }
-                    TileType::DoubleArc => {
-                        self.generate_double_arc_tile(x, y, rotation, &mut curves);
-                    }
-                    TileType::Triangle => {
-                        self.generate_triangle_tile(x, y, rotation, &mut lines);
-                    }
-                    TileType::Maze => {
-                        self.generate_maze_tile(x, y, rotation, &mut lines);
-                    }
-                }
-            }
+        let strokes: Vec<Polyline> = lines
+            .into_iter()
+            .map(|(a, b)| vec![a, b])
+            .chain(curves)
+            .collect();
+
+        // Freely rotated tiles and ribbon edges no longer meet on the tile
+        // lattice
+        if self.free_rotation || self.has_ribbons() {
+            return Ok(strokes);
+        }
+
+        Ok(self.join_strokes(strokes))
+    }
+
+    /// Trace the maze corridors as continuous polylines
+    ///
+    /// For the "maze" tile type: builds the same tiles as `generate`, then
+    /// follows each corridor from tile to tile through the shared edge
+    /// midpoints, so every corridor becomes one long winding stroke instead
+    /// of a pile of center-to-edge segments.
+    ///
+    /// Returns (paths, closed): the corridor polylines and, for each, whether
+    /// it is a closed loop (its first point repeated at the end). Open
+    /// corridors end at dead ends or the canvas edge.
+    fn generate_maze_path(&mut self) -> PyResult<(Vec<Polyline>, Vec<bool>)> {
+        if self.tile_type != TileType::Maze {
+            return Err(AxiArtError::InvalidOption(
+                "generate_maze_path requires the 'maze' tile type".into(),
+            )
+            .into());
+        }
+        if self.free_rotation {
+            return Err(AxiArtError::InvalidOption(
+                "generate_maze_path does not support free_rotation".into(),
+            )
+            .into());
         }
 
-        Ok((lines, curves))
+        let (lines, _) = self.generate_strokes();
+        let paths = self.join_strokes(lines.into_iter().map(|(a, b)| vec![a, b]).collect());
+        let closed = paths
+            .iter()
+            .map(|path| path.len() > 2 && path[0] == path[path.len() - 1])
+            .collect();
+
+        Ok((paths, closed))
+    }
+
+    /// Generate a Wang tiling of edge-matched arcs
+    ///
+    /// Every tile edge gets one of `num_colors` colors, shared with the
+    /// neighbor across it. Tiles are placed row by row: the top and left
+    /// colors come from the tiles already placed, and the right and bottom
+    /// colors are drawn so each color appears on an even number of the
+    /// tile's edges. Same-colored edges are then connected through their
+    /// midpoints - adjacent edges by a quarter arc around their shared corner,
+    /// opposite edges by a straight crossing, and four edges of one color by
+    /// two arcs - so every stroke continues into the next tile and the curves
+    /// run unbroken across the grid. One color gives the classic two-arc
+    /// Truchet; more colors mix in straight crossings.
+    ///
+    /// `tile_type`, `randomness` and rotation settings do not apply.
+    /// Returns the strokes joined into polylines (closed loops repeat their
+    /// first point).
+    #[pyo3(signature = (num_colors=2))]
+    fn generate_wang(&mut self, num_colors: usize) -> PyResult<Vec<Polyline>> {
+        if num_colors == 0 {
+            return Err(AxiArtError::InvalidParameter("num_colors must be positive".into()).into());
+        }
+        let strokes = self.wang_strokes(num_colors);
+        Ok(self.join_strokes(strokes))
     }
 
     /// Get the width of the canvas
@@ -172,34 +363,398 @@ impl TruchetGenerator {
     fn height(&self) -> f64 {
         self.height
     }
+
+    /// Get the seed in use, including one drawn from entropy
+    ///
+    /// Pass it back as `seed` to reproduce a layout you liked. An unseeded
+    /// generator continues its random stream between calls, so this
+    /// reproduces its first layout.
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl TruchetGenerator {
-    /// Generate a diagonal tile (line from one corner to opposite corner)
-    fn generate_diagonal_tile(
+    /// Build every tile, returning (lines, curves)
+    fn generate_strokes(&mut self) -> (Vec<Segment>, Vec<Polyline>) {
+        let mut lines = Vec::new();
+        let mut curves = Vec::new();
+
+        for (col, row, rotation, angle) in self.tile_layout() {
+            self.generate_tile(col, row, rotation, angle, &mut lines, &mut curves);
+        }
+
+        (lines, curves)
+    }
+
+    /// Pick the rotation of every tile, in row-major order
+    ///
+    /// Tiles are random with probability `randomness`; the rest follow the
+    /// noise field when `noise_scale` is set, or a checkerboard otherwise.
+    /// Random rotations are uniform unless `rotation_weights` is set.
+    /// Returns (col, row, rotation, angle) with rotation 0-3 for 0°, 90°,
+    /// 180°, 270°, and `angle` the extra free rotation in radians (always 0
+    /// unless `free_rotation` is set).
+    ///
+    /// A seeded generator restarts its RNG here so every layout it produces
+    /// is the same.
+    fn tile_layout(&mut self) -> Vec<(usize, usize, usize, f64)> {
+        if self.reseed {
+            self.rng = ChaCha8Rng::seed_from_u64(self.seed);
+        }
+
+        let mut layout = Vec::with_capacity(self.cols * self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let rotation = if self.rng.gen::<f64>() < self.randomness {
+                    match &self.rotation_weights {
+                        Some(weights) => weights.sample(&mut self.rng),
+                        None => self.rng.gen_range(0..4),
+                    }
+                } else if let Some(scale) = self.noise_scale {
+                    self.noise_rotation(col, row, scale)
+                } else {
+                    // Use pattern based on position
+                    (col + row) % 2
+                };
+                let angle = if self.free_rotation {
+                    self.free_angle(col, row)
+                } else {
+                    0.0
+                };
+                layout.push((col, row, rotation, angle));
+            }
+        }
+
+        layout
+    }
+
+    /// Rotation from the Perlin field at a tile's center
+    ///
+    /// The noise value is split into eight bands that cycle through the four
+    /// rotations twice, so neighboring tiles share rotations within smooth,
+    /// large-scale regions.
+    fn noise_rotation(&self, col: usize, row: usize, scale: f64) -> usize {
+        let cx = (col as f64 + 0.5) * self.tile_width;
+        let cy = (row as f64 + 0.5) * self.tile_height;
+        let value = self.noise.get([cx / scale, cy / scale]);
+
+        (((value + 1.0) * 4.0).floor() as i64).rem_euclid(4) as usize
+    }
+
+    /// Continuous rotation angle of a tile for `free_rotation`
+    ///
+    /// Follows the noise field when `noise_scale` is set, so nearby tiles turn
+    /// together into swirls; otherwise uniformly random.
+    fn free_angle(&mut self, col: usize, row: usize) -> f64 {
+        match self.noise_scale {
+            Some(scale) => {
+                let cx = (col as f64 + 0.5) * self.tile_width;
+                let cy = (row as f64 + 0.5) * self.tile_height;
+                (self.noise.get([cx / scale, cy / scale]) + 1.0) * PI
+            }
+            None => self.rng.gen::<f64>() * 2.0 * PI,
+        }
+    }
+
+    /// Generate the geometry of one tile
+    ///
+    /// The tile's strokes are turned by `angle` radians about its center.
+    fn generate_tile(
         &self,
-        x: f64,
-        y: f64,
+        col: usize,
+        row: usize,
         rotation: usize,
-        lines: &mut Vec<((f64, f64), (f64, f64))>,
+        angle: f64,
+        lines: &mut Vec<Segment>,
+        curves: &mut Vec<Polyline>,
+    ) {
+        let x = col as f64 * self.tile_width;
+        let y = row as f64 * self.tile_height;
+        let (first_line, first_curve) = (lines.len(), curves.len());
+
+        match self.tile_type {
+            TileType::Diagonal => self.generate_diagonal_tile(x, y, rotation, lines),
+            TileType::Arc => self.generate_arc_tile(x, y, rotation, curves),
+            TileType::DoubleArc => self.generate_double_arc_tile(x, y, rotation, curves),
+            TileType::Triangle => self.generate_triangle_tile(x, y, rotation, lines),
+            TileType::Maze => self.generate_maze_tile(x, y, rotation, lines),
+            TileType::Circle => self.generate_circle_tile(x, y, rotation, curves),
+        }
+
+        if angle != 0.0 {
+            let (cx, cy) = (x + self.tile_width / 2.0, y + self.tile_height / 2.0);
+            let (sin, cos) = angle.sin_cos();
+            let rotate = |(px, py): (f64, f64)| {
+                let (dx, dy) = (px - cx, py - cy);
+                (cx + dx * cos - dy * sin, cy + dx * sin + dy * cos)
+            };
+
+            for line in &mut lines[first_line..] {
+                *line = (rotate(line.0), rotate(line.1));
+            }
+            for curve in &mut curves[first_curve..] {
+                for point in curve.iter_mut() {
+                    *point = rotate(*point);
+                }
+            }
+        }
+    }
+
+    /// Boundary ports joined by each stroke of a tile
+    ///
+    /// Mirrors the geometry of the `generate_*_tile` helpers; see
+    /// `generate_with_connectivity` for the port numbering.
+    fn tile_connections(&self, rotation: usize) -> Vec<(usize, usize)> {
+        match self.tile_type {
+            TileType::Diagonal => match rotation % 2 {
+                0 => vec![(0, 4)],
+                _ => vec![(2, 6)],
+            },
+            // Every rotation runs corner 0 to corner 4; rotation only picks
+            // which corner the arc bulges around
+            TileType::Arc => vec![(0, 4)],
+            TileType::DoubleArc => vec![(0, 4), (0, 4)],
+            TileType::Triangle => match rotation {
+                0 => vec![(0, 2), (2, 6), (6, 0)],
+                1 => vec![(2, 4), (4, 6), (6, 2)],
+                2 => vec![(4, 6), (6, 2), (2, 4)],
+                _ => vec![(6, 0), (0, 4), (4, 6)],
+            },
+            TileType::Maze => match rotation {
+                0 => vec![(7, 1)],
+                1 => vec![(1, 3)],
+                2 => vec![(3, 5)],
+                _ => vec![(5, 7)],
+            },
+            // The inscribed circle and the four corner dots both run through
+            // every edge midpoint
+            TileType::Circle => vec![(1, 3), (3, 5), (5, 7), (7, 1)],
+        }
+    }
+
+    /// Stitch strokes that share endpoints into continuous polylines
+    ///
+    /// Every stroke starts and ends on a corner, edge midpoint or center of a
+    /// tile, so endpoints are matched exactly by snapping them to the
+    /// half-tile lattice.
+    fn join_strokes(&self, strokes: Vec<Polyline>) -> Vec<Polyline> {
+        let (half_w, half_h) = (self.tile_width / 2.0, self.tile_height / 2.0);
+        let node = |(x, y): (f64, f64)| ((x / half_w).round() as i64, (y / half_h).round() as i64);
+
+        let ends: Vec<[(i64, i64); 2]> = strokes
+            .iter()
+            .map(|stroke| [node(stroke[0]), node(stroke[stroke.len() - 1])])
+            .collect();
+
+        let mut incident: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        for (idx, stroke_ends) in ends.iter().enumerate() {
+            for &end in stroke_ends {
+                incident.entry(end).or_default().push(idx);
+            }
+        }
+
+        // The unvisited stroke continuing through `at`, if the path can go on
+        let next_stroke = |at: (i64, i64), current: usize, used: &[bool]| {
+            let list = &incident[&at];
+            if list.len() != 2 {
+                return None; // Junction or dead end
+            }
+            list.iter()
+                .copied()
+                .find(|&idx| idx != current && !used[idx])
+        };
+
+        let mut used = vec![false; strokes.len()];
+        let mut paths = Vec::new();
+
+        for start in 0..strokes.len() {
+            if used[start] {
+                continue;
+            }
+            used[start] = true;
+
+            // Walk forward from the stroke's end
+            let mut path = strokes[start].clone();
+            let (mut at, mut current) = (ends[start][1], start);
+            while let Some(next) = next_stroke(at, current, &used) {
+                used[next] = true;
+                let forward = ends[next][0] == at;
+                at = if forward {
+                    ends[next][1]
+                } else {
+                    ends[next][0]
+                };
+                Self::append_stroke(&mut path, &strokes[next], forward);
+                current = next;
+            }
+
+            if at == ends[start][0] && path.len() > 2 {
+                // Came back around: close the loop exactly
+                let first = path[0];
+                *path.last_mut().unwrap() = first;
+                paths.push(path);
+                continue;
+            }
+
+            // Walk backward from the stroke's start, collecting strokes
+            // oriented to end where the path begins
+            let mut parts: Vec<Polyline> = Vec::new();
+            let (mut at, mut current) = (ends[start][0], start);
+            while let Some(next) = next_stroke(at, current, &used) {
+                used[next] = true;
+                if ends[next][1] == at {
+                    at = ends[next][0];
+                    parts.push(strokes[next].clone());
+                } else {
+                    at = ends[next][1];
+                    parts.push(strokes[next].iter().rev().copied().collect());
+                }
+                current = next;
+            }
+
+            let mut joined = Vec::with_capacity(path.len());
+            for part in parts.iter().rev() {
+                joined.extend_from_slice(&part[..part.len() - 1]);
+            }
+            joined.extend(path);
+            paths.push(joined);
+        }
+
+        paths
+    }
+
+    /// Place a Wang tiling and return its unjoined strokes
+    fn wang_strokes(&mut self, num_colors: usize) -> Vec<Polyline> {
+        if self.reseed {
+            self.rng = ChaCha8Rng::seed_from_u64(self.seed);
+        }
+
+        // Color of the bottom edge of the previous row, per column
+        let mut above: Vec<usize> = (0..self.cols)
+            .map(|_| self.rng.gen_range(0..num_colors))
+            .collect();
+        let mut strokes = Vec::new();
+
+        for row in 0..self.rows {
+            let mut left = self.rng.gen_range(0..num_colors);
+            for (col, top) in above.iter_mut().enumerate() {
+                let (right, bottom) = if *top == left {
+                    let color = self.rng.gen_range(0..num_colors);
+                    (color, color)
+                } else if self.rng.gen::<bool>() {
+                    (*top, left)
+                } else {
+                    (left, *top)
+                };
+
+                self.wang_tile(col, row, [*top, right, bottom, left], &mut strokes);
+                *top = bottom;
+                left = right;
+            }
+        }
+
+        strokes
+    }
+
+    /// Strokes of one Wang tile with edge colors [top, right, bottom, left]
+    fn wang_tile(
+        &mut self,
+        col: usize,
+        row: usize,
+        colors: [usize; 4],
+        strokes: &mut Vec<Polyline>,
     ) {
-        let s = self.tile_size;
+        let (x, y) = (col as f64 * self.tile_width, row as f64 * self.tile_height);
+        let (w, h) = (self.tile_width, self.tile_height);
+        let midpoints = [
+            (x + w / 2.0, y),
+            (x + w, y + h / 2.0),
+            (x + w / 2.0, y + h),
+            (x, y + h / 2.0),
+        ];
+        // Corner shared by edge i and edge (i + 1) % 4
+        let corners = [(x + w, y), (x + w, y + h), (x, y + h), (x, y)];
+
+        let mut pairs = Vec::new();
+        if colors.iter().all(|&c| c == colors[0]) {
+            // Two arcs around opposite corners, either diagonal
+            if self.rng.gen::<bool>() {
+                pairs.extend([(0, 1), (2, 3)]);
+            } else {
+                pairs.extend([(1, 2), (0, 3)]);
+            }
+        } else {
+            for a in 0..4 {
+                for b in a + 1..4 {
+                    if colors[a] == colors[b] {
+                        pairs.push((a, b));
+                    }
+                }
+            }
+        }
+
+        for (a, b) in pairs {
+            if b - a == 2 {
+                strokes.push(vec![midpoints[a], midpoints[b]]);
+                continue;
+            }
+            // Adjacent edges: quarter ellipse around their shared corner
+            let corner = if (a + 1) % 4 == b { a } else { b };
+            let (cx, cy) = corners[corner];
+            let angle = |(px, py): (f64, f64)| ((py - cy) / (h / 2.0)).atan2((px - cx) / (w / 2.0));
+            let start = angle(midpoints[a]);
+            let sweep = (angle(midpoints[b]) - start + PI).rem_euclid(2.0 * PI) - PI;
+
+            let mut arc: Vec<(f64, f64)> = (0..=self.arc_segments)
+                .map(|i| {
+                    let t = start + sweep * i as f64 / self.arc_segments as f64;
+                    (cx + w / 2.0 * t.cos(), cy + h / 2.0 * t.sin())
+                })
+                .collect();
+            // Land exactly on the midpoints so strokes join across tiles
+            arc[0] = midpoints[a];
+            *arc.last_mut().unwrap() = midpoints[b];
+            strokes.push(arc);
+        }
+    }
+
+    /// Append a stroke to a path whose last point is the stroke's shared end
+    fn append_stroke(path: &mut Vec<(f64, f64)>, stroke: &[(f64, f64)], forward: bool) {
+        if forward {
+            path.extend(stroke.iter().skip(1).copied());
+        } else {
+            path.extend(stroke.iter().rev().skip(1).copied());
+        }
+    }
+
+    /// Generate a diagonal tile (line from one corner to opposite corner)
+    fn generate_diagonal_tile(&self, x: f64, y: f64, rotation: usize, lines: &mut Vec<Segment>) {
+        let (w, h) = (self.tile_width, self.tile_height);
         let (p1, p2) = match rotation % 2 {
-            0 => ((x, y), (x + s, y + s)), // Top-left to bottom-right
-            _ => ((x + s, y), (x, y + s)), // Top-right to bottom-left
+            0 => ((x, y), (x + w, y + h)), // Top-left to bottom-right
+            _ => ((x + w, y), (x, y + h)), // Top-right to bottom-left
         };
         lines.push((p1, p2));
     }
 
     /// Generate an arc tile (quarter circle from one edge to adjacent edge)
-    fn generate_arc_tile(
-        &self,
-        x: f64,
-        y: f64,
-        rotation: usize,
-        curves: &mut Vec<Vec<(f64, f64)>>,
-    ) {
-        let s = self.tile_size;
+    fn generate_arc_tile(&self, x: f64, y: f64, rotation: usize, curves: &mut Vec<Polyline>) {
+        let (w, h) = (self.tile_width, self.tile_height);
         let mut points = Vec::new();
 
         // Generate arc based on rotation
@@ -211,26 +766,26 @@ impl TruchetGenerator {
             let (px, py) = match rotation {
                 0 => {
                     // Arc from left edge to bottom edge, curved around bottom-left
-                    (x + s * (1.0 - angle.cos()), y + s * angle.sin())
+                    (x + w * (1.0 - angle.cos()), y + h * angle.sin())
                 }
                 1 => {
                     // Arc from bottom edge to right edge, curved around bottom-right
-                    (x + s * angle.sin(), y + s * (1.0 - angle.cos()))
+                    (x + w * angle.sin(), y + h * (1.0 - angle.cos()))
                 }
                 2 => {
                     // Arc from right edge to top edge, curved around top-right
-                    (x + s * angle.cos(), y + s * (1.0 - angle.sin()))
+                    (x + w * angle.cos(), y + h * (1.0 - angle.sin()))
                 }
                 _ => {
                     // Arc from top edge to left edge, curved around top-left
-                    (x + s * (1.0 - angle.sin()), y + s * angle.cos())
+                    (x + w * (1.0 - angle.sin()), y + h * angle.cos())
                 }
             };
 
             points.push((px, py));
         }
 
-        curves.push(points);
+        self.push_arc(Self::arc_center(x, y, w, h, rotation), points, curves);
     }
 
     /// Generate a double arc tile (two quarter circles)
@@ -239,9 +794,9 @@ impl TruchetGenerator {
         x: f64,
         y: f64,
         rotation: usize,
-        curves: &mut Vec<Vec<(f64, f64)>>,
+        curves: &mut Vec<Polyline>,
     ) {
-        let s = self.tile_size;
+        let (w, h) = (self.tile_width, self.tile_height);
 
         // Two arcs per tile
         for arc_idx in 0..2 {
@@ -252,17 +807,17 @@ impl TruchetGenerator {
                 let angle = t * PI / 2.0;
 
                 let (px, py) = match (rotation, arc_idx) {
-                    (0, 0) => (x + s * (1.0 - angle.cos()), y + s * angle.sin()),
-                    (0, _) => (x + s * angle.cos(), y + s * (1.0 - angle.sin())),
-                    (1, 0) => (x + s * angle.sin(), y + s * (1.0 - angle.cos())),
-                    (1, _) => (x + s * (1.0 - angle.sin()), y + s * angle.cos()),
-                    (2, 0) => (x + s * angle.cos(), y + s * (1.0 - angle.sin())),
-                    (2, _) => (x + s * (1.0 - angle.cos()), y + s * angle.sin()),
+                    (0, 0) => (x + w * (1.0 - angle.cos()), y + h * angle.sin()),
+                    (0, _) => (x + w * angle.cos(), y + h * (1.0 - angle.sin())),
+                    (1, 0) => (x + w * angle.sin(), y + h * (1.0 - angle.cos())),
+                    (1, _) => (x + w * (1.0 - angle.sin()), y + h * angle.cos()),
+                    (2, 0) => (x + w * angle.cos(), y + h * (1.0 - angle.sin())),
+                    (2, _) => (x + w * (1.0 - angle.cos()), y + h * angle.sin()),
                     _ => {
                         if arc_idx == 0 {
-                            (x + s * (1.0 - angle.sin()), y + s * angle.cos())
+                            (x + w * (1.0 - angle.sin()), y + h * angle.cos())
                         } else {
-                            (x + s * angle.sin(), y + s * (1.0 - angle.cos()))
+                            (x + w * angle.sin(), y + h * (1.0 - angle.cos()))
                         }
                     }
                 };
@@ -270,25 +825,87 @@ impl TruchetGenerator {
                 points.push((px, py));
             }
 
+            // The second arc is the first arc of the opposite rotation
+            let center = Self::arc_center(x, y, w, h, (rotation + 2 * arc_idx) % 4);
+            self.push_arc(center, points, curves);
+        }
+    }
+
+    /// Center of the quarter circle an arc tile with `rotation` curves around
+    fn arc_center(x: f64, y: f64, w: f64, h: f64, rotation: usize) -> (f64, f64) {
+        match rotation {
+            0 | 3 => (x + w, y),
+            _ => (x, y + h),
+        }
+    }
+
+    /// Whether arc strokes are drawn as ribbons of two offset curves
+    fn has_ribbons(&self) -> bool {
+        self.arc_width > 0.0 && matches!(self.tile_type, TileType::Arc | TileType::DoubleArc)
+    }
+
+    /// Add an arc stroke, or the two edges of its ribbon with `arc_width`
+    ///
+    /// The edges are the arc rescaled about its `center` to radius
+    /// ± arc_width / 2, so each ends arc_width / 2 either side of the arc's
+    /// corner along the tile edge: the same points as the edges of a
+    /// neighbor's ribbon continuing the arc. Ribbons are clipped to the tiled
+    /// area, and an end on its border, where no neighbor continues it, is
+    /// closed with a straight cap.
+    fn push_arc(&self, center: (f64, f64), points: Vec<(f64, f64)>, curves: &mut Vec<Polyline>) {
+        if self.arc_width <= 0.0 {
             curves.push(points);
+            return;
+        }
+
+        let (w, h) = (self.tile_width, self.tile_height);
+        let grid = Rect::new(0.0, 0.0, self.cols as f64 * w, self.rows as f64 * h);
+        // First and last point of each edge after clipping
+        let mut ends = Vec::with_capacity(2);
+        for offset in [-self.arc_width / 2.0, self.arc_width / 2.0] {
+            let (sx, sy) = ((w + offset) / w, (h + offset) / h);
+            let edge: Vec<(f64, f64)> = points
+                .iter()
+                .map(|&(px, py)| {
+                    (
+                        center.0 + (px - center.0) * sx,
+                        center.1 + (py - center.1) * sy,
+                    )
+                })
+                .collect();
+            let pieces = clip_polyline_to_rect(&edge, &grid);
+            if let (Some(first), Some(last)) = (pieces.first(), pieces.last()) {
+                ends.push((first[0], last[last.len() - 1]));
+            }
+            curves.extend(pieces);
+        }
+
+        let eps = 1e-9 * (w + h);
+        let on_border = |(px, py): (f64, f64)| {
+            (px - grid.x0).abs() < eps
+                || (px - grid.x1).abs() < eps
+                || (py - grid.y0).abs() < eps
+                || (py - grid.y1).abs() < eps
+        };
+        if let [inner, outer] = ends[..] {
+            if points.first().is_some_and(|&p| on_border(p)) {
+                curves.push(vec![inner.0, outer.0]);
+            }
+            if points.last().is_some_and(|&p| on_border(p)) {
+                curves.push(vec![inner.1, outer.1]);
+            }
         }
     }
 
     /// Generate a triangle tile
-    fn generate_triangle_tile(
-        &self,
-        x: f64,
-        y: f64,
-        rotation: usize,
-        lines: &mut Vec<((f64, f64), (f64, f64))>,
-    ) {
-        let s = self.tile_size;
+    fn generate_triangle_tile(&self, x: f64, y: f64, rotation: usize, lines: &mut Vec<Segment>) {
+        let (w, h) = (self.tile_width, self.tile_height);
 
         let points = match rotation {
-            0 => vec![(x, y), (x + s, y), (x, y + s)],
-            1 => vec![(x + s, y), (x + s, y + s), (x, y + s)],
-            2 => vec![(x + s, y + s), (x, y + s), (x + s, y)],
-            _ => vec![(x, y + s), (x, y), (x + s, y + s)],
+            0 => vec![(x, y), (x + w, y), (x, y + h)],
+            1 => vec![(x + w, y), (x + w, y + h), (x, y + h)],
+            2 => vec![(x + w, y + h), (x, y + h), (x + w, y)],
+            _ => vec![(x, y + h), (x, y), (x + w, y + h)],
         };
 
         // Draw triangle edges
@@ -300,16 +917,10 @@ impl TruchetGenerator {
     }
 
     /// Generate a maze-like tile (lines from center to edges)
-    fn generate_maze_tile(
-        &self,
-        x: f64,
-        y: f64,
-        rotation: usize,
-        lines: &mut Vec<((f64, f64), (f64, f64))>,
-    ) {
-        let s = self.tile_size;
-        let cx = x + s / 2.0;
-        let cy = y + s / 2.0;
+    fn generate_maze_tile(&self, x: f64, y: f64, rotation: usize, lines: &mut Vec<Segment>) {
+        let (w, h) = (self.tile_width, self.tile_height);
+        let cx = x + w / 2.0;
+        let cy = y + h / 2.0;
 
         // Draw lines from center to specific edges based on rotation
         match rotation {
@@ -319,16 +930,157 @@ impl TruchetGenerator {
             }
             1 => {
                 lines.push(((cx, cy), (cx, y))); // Top
-                lines.push(((cx, cy), (x + s, cy))); // Right
+                lines.push(((cx, cy), (x + w, cy))); // Right
             }
             2 => {
-                lines.push(((cx, cy), (x + s, cy))); // Right
-                lines.push(((cx, cy), (cx, y + s))); // Bottom
+                lines.push(((cx, cy), (x + w, cy))); // Right
+                lines.push(((cx, cy), (cx, y + h))); // Bottom
             }
             _ => {
-                lines.push(((cx, cy), (cx, y + s))); // Bottom
+                lines.push(((cx, cy), (cx, y + h))); // Bottom
                 lines.push(((cx, cy), (x, cy))); // Left
             }
         }
     }
+
+    /// Generate a circle tile
+    ///
+    /// Even rotations draw a full circle inscribed in the tile; odd rotations
+    /// draw a quarter-circle dot around each corner, which combine with the
+    /// neighboring tiles' dots into circles centered on the grid points.
+    /// Non-square tiles get ellipses.
+    fn generate_circle_tile(&self, x: f64, y: f64, rotation: usize, curves: &mut Vec<Polyline>) {
+        let (rx, ry) = (self.tile_width / 2.0, self.tile_height / 2.0);
+
+        if rotation.is_multiple_of(2) {
+            let (cx, cy) = (x + rx, y + ry);
+            let segments = self.arc_segments * 4;
+            let points = (0..=segments)
+                .map(|i| {
+                    let angle = 2.0 * PI * (i % segments) as f64 / segments as f64;
+                    (cx + rx * angle.cos(), cy + ry * angle.sin())
+                })
+                .collect();
+            curves.push(points);
+            return;
+        }
+
+        // Corner, and the angle where its quarter circle starts
+        let corners = [
+            ((x, y), 0.0),
+            ((x + 2.0 * rx, y), PI / 2.0),
+            ((x + 2.0 * rx, y + 2.0 * ry), PI),
+            ((x, y + 2.0 * ry), 3.0 * PI / 2.0),
+        ];
+        for ((cx, cy), start) in corners {
+            let points = (0..=self.arc_segments)
+                .map(|i| {
+                    let angle = start + (i as f64 / self.arc_segments as f64) * PI / 2.0;
+                    (cx + rx * angle.cos(), cy + ry * angle.sin())
+                })
+                .collect();
+            curves.push(points);
+        }
+    }
+}
+
+impl Generator for TruchetGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        let (lines, curves) = self.generate_strokes();
+        let mut paths = Paths::from_segments(lines);
+        paths.0.extend(curves);
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_generator(seed: u64) -> TruchetGenerator {
+        TruchetGenerator {
+            width: 297.0,
+            height: 210.0,
+            tile_type: TileType::Arc,
+            grid_size: 20,
+            cols: 29,
+            rows: 20,
+            tile_width: 10.5,
+            tile_height: 10.5,
+            randomness: 0.5,
+            arc_segments: 16,
+            arc_width: 0.0,
+            noise_scale: None,
+            noise: Perlin::new(0),
+            rotation_weights: None,
+            free_rotation: false,
+            seed,
+            reseed: true,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    #[test]
+    fn same_seed_produces_identical_patterns() {
+        let first = seeded_generator(42).generate_strokes();
+        let second = seeded_generator(42).generate_strokes();
+        assert_eq!(first, second);
+
+        // Different seeds pick different rotations
+        let other = seeded_generator(43).generate_strokes();
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn ribbon_ends_meet_the_continuing_neighbor() {
+        let mut generator = seeded_generator(1);
+        generator.arc_width = 2.0;
+        let (w, h) = (generator.tile_width, generator.tile_height);
+
+        // Tile (0, 0) leaves its bottom-right corner heading right, which
+        // tile (1, 1) continues from its top-left corner
+        let mut first = Vec::new();
+        generator.generate_arc_tile(0.0, 0.0, 0, &mut first);
+        let mut second = Vec::new();
+        generator.generate_arc_tile(w, h, 1, &mut second);
+
+        let corner = (w, h);
+        let near_corner = |curves: &[Polyline]| {
+            let mut points: Vec<(f64, f64)> = curves
+                .iter()
+                .flat_map(|curve| [curve[0], curve[curve.len() - 1]])
+                .filter(|&(x, y)| (x - corner.0).hypot(y - corner.1) < 1.5)
+                .collect();
+            points.sort_by(|a, b| a.1.total_cmp(&b.1));
+            points
+        };
+        let (a, b) = (near_corner(&first), near_corner(&second));
+        assert_eq!(a.len(), 2);
+        assert_eq!(b.len(), 2);
+        for (p, q) in a.iter().zip(&b) {
+            assert!((p.0 - q.0).abs() < 1e-9 && (p.1 - q.1).abs() < 1e-9);
+        }
+        assert!((a[0].1 - (h - 1.0)).abs() < 1e-9 && (a[1].1 - (h + 1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ribbon_ends_on_the_grid_border_are_capped() {
+        let mut generator = seeded_generator(1);
+        generator.arc_width = 2.0;
+        let mut curves = Vec::new();
+        // Starts at the top-left corner of the grid, ends inside it
+        generator.generate_arc_tile(0.0, 0.0, 0, &mut curves);
+        assert_eq!(curves.len(), 3);
+        let cap = &curves[2];
+        assert_eq!(cap.len(), 2);
+        assert!(cap.iter().all(|&(x, y)| x >= 0.0 && y >= 0.0));
+    }
+
+    #[test]
+    fn repeated_generate_is_reproducible() {
+        let mut generator = seeded_generator(7);
+        let first = generator.generate_strokes();
+        let second = generator.generate_strokes();
+        assert_eq!(first, second);
+    }
 }
@@ -1,12 +1,71 @@
 //! High-performance Voronoi diagram generator
 //!
-//! Generates Voronoi diagrams using a sampling-based approach optimized for pen plotting.
-//! Supports Lloyd's relaxation for more uniform cell distribution.
+//! Generates Voronoi diagrams using a sampling-based approach optimized for pen plotting,
+//! or exact edges from the dual of a Delaunay triangulation.
+//! Supports Lloyd's relaxation for more uniform cell distribution. The sampling
+//! grid is filled in parallel with rayon.
 
+use crate::distance::Metric;
+use crate::error::AxiArtError;
+use crate::kdtree::KdTree;
+use crate::paths::{points_to_array, Bounds, Generator, Paths};
+use crate::seed::resolve_seed;
+use crate::spatial_hash::SpatialHash;
+use crate::threads;
+use crate::{cancel, delaunay, geometry, optimize};
+use numpy::{PyArray2, PyReadonlyArray2};
 use pyo3::prelude::*;
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
-use std::collections::HashSet;
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+
+/// Cell edge as ((x1, y1), (x2, y2))
+type Segment = ((f64, f64), (f64, f64));
+
+/// Sites and the cell edges between them
+type Diagram = (Vec<(f64, f64)>, Vec<Segment>);
+
+/// Sites and the index pairs of neighboring ones
+type SiteGraph = (Vec<(f64, f64)>, Vec<(usize, usize)>);
+
+/// Direction tolerance (radians) when merging sampled edge fragments
+const MERGE_ANGLE_TOLERANCE: f64 = 1e-3;
+/// Endpoint/offset tolerance when merging sampled edge fragments
+const MERGE_POSITION_TOLERANCE: f64 = 1e-6;
+
+/// Default centroid samples per site spacing for Lloyd's relaxation
+const DEFAULT_RELAXATION_SAMPLES: usize = 50;
+
+/// Candidates tried around each active point in Poisson-disk sampling
+const POISSON_CANDIDATES: usize = 30;
+/// Points per squared spacing that Poisson-disk sampling fills an area with
+const POISSON_DENSITY: f64 = 0.65;
+
+/// How the initial sites are scattered over the canvas
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SiteDistribution {
+    /// Independent uniform points
+    Random,
+    /// One uniform point per cell of a grid
+    JitteredGrid,
+    /// Blue noise with a minimum spacing between sites
+    Poisson,
+}
+
+impl SiteDistribution {
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s.to_lowercase().as_str() {
+            "random" => Ok(SiteDistribution::Random),
+            "jittered_grid" => Ok(SiteDistribution::JitteredGrid),
+            "poisson" => Ok(SiteDistribution::Poisson),
+            _ => Err(AxiArtError::InvalidOption(
+                "Invalid site distribution. Use 'random', 'jittered_grid', or 'poisson'".into(),
+            )
+            .into()),
+        }
+    }
+}
 
 /// High-performance Voronoi Diagram Generator
 ///
@@ -32,14 +91,23 @@ pub struct VoronoiGenerator {
     height: f64,
     num_sites: usize,
     relaxation_iterations: usize,
+    relaxation_samples: usize,
     clip_to_bounds: bool,
     sampling_resolution: usize,
+    metric: Metric,
+    clip_polygon: Option<Vec<(f64, f64)>>,
+    site_distribution: SiteDistribution,
+    seed: u64,
     rng: ChaCha8Rng,
 }
 
 #[pymethods]
 impl VoronoiGenerator {
     #[new]
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "each argument is a Python keyword argument"
+    )]
     #[pyo3(signature = (
         width=297.0,
         height=210.0,
@@ -47,7 +115,12 @@ impl VoronoiGenerator {
         relaxation_iterations=0,
         clip_to_bounds=true,
         sampling_resolution=800,
-        seed=None
+        seed=None,
+        metric="euclidean",
+        p=2.0,
+        clip_polygon=None,
+        relaxation_samples=DEFAULT_RELAXATION_SAMPLES,
+        site_distribution="random"
     ))]
     fn new(
         width: f64,
@@ -57,20 +130,42 @@ impl VoronoiGenerator {
         clip_to_bounds: bool,
         sampling_resolution: usize,
         seed: Option<u64>,
+        metric: &str,
+        p: f64,
+        clip_polygon: Option<Vec<(f64, f64)>>,
+        relaxation_samples: usize,
+        site_distribution: &str,
     ) -> PyResult<Self> {
-        let rng = if let Some(s) = seed {
-            ChaCha8Rng::seed_from_u64(s)
-        } else {
-            ChaCha8Rng::from_entropy()
-        };
+        let metric = Metric::from_str(metric, p)?;
+        let site_distribution = SiteDistribution::from_str(site_distribution)?;
+        if clip_polygon.as_ref().is_some_and(|poly| poly.len() < 3) {
+            return Err(AxiArtError::InvalidParameter(
+                "clip_polygon needs at least 3 vertices".into(),
+            )
+            .into());
+        }
+        if relaxation_samples == 0 {
+            return Err(AxiArtError::InvalidParameter(
+                "relaxation_samples must be positive".into(),
+            )
+            .into());
+        }
+        // Draw a seed from entropy when none is given, so it can be reported
+        let seed = resolve_seed(seed).unwrap_or_else(|| rand::thread_rng().gen());
+        let rng = ChaCha8Rng::seed_from_u64(seed);
 
         Ok(VoronoiGenerator {
             width,
             height,
             num_sites,
             relaxation_iterations,
+            relaxation_samples,
             clip_to_bounds,
             sampling_resolution,
+            metric,
+            clip_polygon,
+            site_distribution,
+            seed,
             rng,
         })
     }
@@ -81,25 +176,225 @@ impl VoronoiGenerator {
     /// - sites: List of (x, y) coordinates for Voronoi sites
     /// - edges: List of ((x1, y1), (x2, y2)) tuples representing cell boundaries
     ///
-    /// Uses sampling-based edge detection for clean pen-plotter output.
-    fn generate(&mut self) -> PyResult<(Vec<(f64, f64)>, Vec<((f64, f64), (f64, f64))>)> {
-        // Generate initial random sites
-        let mut sites: Vec<(f64, f64)> = (0..self.num_sites)
-            .map(|_| {
-                (
-                    self.rng.gen::<f64>() * self.width,
-                    self.rng.gen::<f64>() * self.height,
+    /// Uses sampling-based edge detection for clean pen-plotter output. Opt
+    /// in with `merge_edges` to join runs of collinear per-sample fragments
+    /// into single long segments, cutting the edge count and pen lifts.
+    ///
+    /// `should_cancel` is an optional callable polled before each relaxation
+    /// iteration; when it returns True, relaxation stops and the diagram is
+    /// built from the sites as relaxed so far.
+    #[pyo3(signature = (merge_edges=false, should_cancel=None))]
+    fn generate(
+        &mut self,
+        py: Python<'_>,
+        merge_edges: bool,
+        should_cancel: Option<PyObject>,
+    ) -> PyResult<Diagram> {
+        py.allow_threads(|| {
+            let sites = self.generate_sites(should_cancel.as_ref())?;
+
+            // Generate edges using sampling-based approach
+            let mut edges = self.detect_edges(&sites);
+            if merge_edges {
+                edges = geometry::merge_collinear_segments(
+                    &edges,
+                    MERGE_ANGLE_TOLERANCE,
+                    MERGE_POSITION_TOLERANCE,
+                );
+            }
+            let edges = self.clip_edges(edges);
+
+            Ok((sites, edges))
+        })
+    }
+
+    /// Generate the Voronoi diagram with exact edges
+    ///
+    /// Returns the same (sites, edges) tuple as `generate`, but each edge is a
+    /// single clean segment of a perpendicular bisector between neighboring
+    /// sites instead of a run of sampled grid steps. Cells are computed from the
+    /// Delaunay triangulation (the Voronoi dual) and clipped to the canvas, so
+    /// there are no duplicate or stair-stepped segments. `sampling_resolution`
+    /// is not used.
+    ///
+    /// Only available for the Euclidean metric: under other metrics the cell
+    /// boundaries are not straight bisectors.
+    fn generate_exact(&mut self) -> PyResult<Diagram> {
+        if self.metric != Metric::Euclidean {
+            return Err(AxiArtError::InvalidOption(
+                "generate_exact requires the 'euclidean' metric; use generate() for other metrics"
+                    .into(),
+            )
+            .into());
+        }
+
+        let sites = self.generate_sites(None)?;
+        let edges = self.clip_edges(self.exact_edges(&sites));
+
+        Ok((sites, edges))
+    }
+
+    /// Generate the Voronoi cells as closed polygons
+    ///
+    /// Returns one ordered, closed polygon (first point repeated at the end)
+    /// per site, clipped to the canvas, for filling, hatching or coloring cells
+    /// individually.
+    ///
+    /// With the Euclidean metric the cells are exact. Other metrics trace each
+    /// boundary along `angular_samples` rays from the site, which is accurate
+    /// to within a ray spacing at sharp cell corners.
+    ///
+    /// With a `clip_polygon`, each cell is intersected with it; this treats
+    /// the cell as convex, which only Euclidean cells are guaranteed to be.
+    #[pyo3(signature = (angular_samples=360))]
+    fn generate_cells(&mut self, angular_samples: usize) -> PyResult<Vec<Vec<(f64, f64)>>> {
+        let sites = self.generate_sites(None)?;
+
+        let cells = if self.metric == Metric::Euclidean {
+            self.exact_cells(&sites)
+        } else {
+            (0..sites.len())
+                .map(|site| self.traced_cell(site, &sites, angular_samples.max(8)))
+                .collect()
+        };
+
+        Ok(self.clip_cells(cells))
+    }
+
+    /// Generate the site adjacency graph
+    ///
+    /// Returns a tuple of (sites, pairs) where each pair (i, j), i < j, holds
+    /// the indices of two sites whose cells share a boundary. Pairs are found
+    /// during the same sampled neighbor scan `generate` uses, so they follow
+    /// the configured metric and `sampling_resolution`; pairs are sorted.
+    fn generate_adjacency(&mut self) -> PyResult<SiteGraph> {
+        let sites = self.generate_sites(None)?;
+        let pairs = self.detect_adjacency(&sites);
+
+        Ok((sites, pairs))
+    }
+
+    /// Generate the Voronoi diagram for caller-supplied sites
+    ///
+    /// Skips random site generation; the given sites are optionally relaxed
+    /// and then run through edge detection (sampled, or exact when `exact` is
+    /// true). Returns (sites, edges) like `generate`, with the sites after
+    /// relaxation. `merge_edges` applies to sampled edges as in `generate`.
+    ///
+    /// Raises ValueError if no sites are given or any site lies outside the
+    /// canvas. Sites outside the `clip_polygon` are dropped. `should_cancel`
+    /// stops relaxation early as in `generate`.
+    #[pyo3(signature = (
+        sites,
+        relaxation_iterations=0,
+        exact=false,
+        merge_edges=false,
+        should_cancel=None
+    ))]
+    fn generate_from_sites(
+        &self,
+        py: Python<'_>,
+        sites: Vec<(f64, f64)>,
+        relaxation_iterations: usize,
+        exact: bool,
+        merge_edges: bool,
+        should_cancel: Option<PyObject>,
+    ) -> PyResult<Diagram> {
+        if sites.is_empty() {
+            return Err(
+                AxiArtError::InvalidParameter("At least one site is required".into()).into(),
+            );
+        }
+        if let Some(&(x, y)) = sites
+            .iter()
+            .find(|&&(x, y)| !(0.0..=self.width).contains(&x) || !(0.0..=self.height).contains(&y))
+        {
+            return Err(AxiArtError::OutOfBounds(format!(
+                "Site ({}, {}) is outside the canvas bounds ({} x {})",
+                x, y, self.width, self.height
+            ))
+            .into());
+        }
+        if exact && self.metric != Metric::Euclidean {
+            return Err(AxiArtError::InvalidOption(
+                "exact edges require the 'euclidean' metric".into(),
+            )
+            .into());
+        }
+
+        let mut sites = sites;
+        sites.retain(|&(x, y)| self.inside_clip(x, y));
+
+        py.allow_threads(|| {
+            let sites = self.relax(sites, relaxation_iterations, should_cancel.as_ref())?;
+
+            let edges = if exact {
+                self.exact_edges(&sites)
+            } else if merge_edges {
+                geometry::merge_collinear_segments(
+                    &self.detect_edges(&sites),
+                    MERGE_ANGLE_TOLERANCE,
+                    MERGE_POSITION_TOLERANCE,
                 )
-            })
-            .collect();
+            } else {
+                self.detect_edges(&sites)
+            };
+            let edges = self.clip_edges(edges);
 
-        // Apply Lloyd's relaxation if requested
-        for _ in 0..self.relaxation_iterations {
-            sites = self.lloyd_relaxation(&sites);
+            Ok((sites, edges))
+        })
+    }
+
+    /// Apply Lloyd's relaxation to caller-supplied sites
+    ///
+    /// A standalone centroidal Voronoi tessellation (CVT) solver for
+    /// stippling and even point distributions: takes an (n, 2) array of
+    /// sites and returns them after `iterations` rounds, as an (n, 2) array
+    /// in the same order. Uses this generator's canvas, metric, clip polygon
+    /// and `relaxation_samples`, but no edge detection. The centroid sampling
+    /// runs in parallel and gives the same result on any number of threads.
+    ///
+    /// Raises ValueError if `sites` is not an (n, 2) array.
+    #[pyo3(signature = (sites, iterations=1))]
+    fn relax_sites<'py>(
+        &self,
+        py: Python<'py>,
+        sites: PyReadonlyArray2<'py, f64>,
+        iterations: usize,
+    ) -> PyResult<Bound<'py, PyArray2<f64>>> {
+        let sites = sites.as_array();
+        if sites.ncols() != 2 {
+            return Err(AxiArtError::InvalidParameter(
+                "sites must be an (n, 2) array of x, y rows".into(),
+            )
+            .into());
         }
+        let sites: Vec<(f64, f64)> = sites
+            .rows()
+            .into_iter()
+            .map(|row| (row[0], row[1]))
+            .collect();
+
+        let relaxed = py.allow_threads(|| self.relax(sites, iterations, None))?;
+        Ok(points_to_array(py, &relaxed))
+    }
 
-        // Generate edges using sampling-based approach
-        let edges = self.detect_edges(&sites);
+    /// Generate the Delaunay triangulation of the sites
+    ///
+    /// Returns a tuple of (sites, edges) where edges are the unique triangle
+    /// edges as ((x1, y1), (x2, y2)) tuples. This is the dual of the Voronoi
+    /// diagram: every edge joins two sites whose cells share a boundary.
+    /// Sites are generated and relaxed exactly as in `generate`; the
+    /// triangulation itself is always Euclidean.
+    fn generate_delaunay(&mut self) -> PyResult<Diagram> {
+        let sites = self.generate_sites(None)?;
+
+        let triangles = delaunay::triangulate(&sites);
+        let edges = delaunay::triangle_edges(&triangles)
+            .into_iter()
+            .map(|(a, b)| (sites[a], sites[b]))
+            .collect();
+        let edges = self.clip_edges(edges);
 
         Ok((sites, edges))
     }
@@ -115,91 +410,343 @@ impl VoronoiGenerator {
     fn height(&self) -> f64 {
         self.height
     }
+
+    /// Get the seed in use, including one drawn from entropy
+    ///
+    /// Pass it back as `seed` to a new generator to reproduce its first
+    /// result; later calls continue the same random stream.
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate with default parameters as a list of polylines
+    ///
+    /// Uniform output for the path utilities such as `to_svg`,
+    /// `clip_to_rect` or `reorder_paths`.
+    fn paths(&mut self) -> PyResult<Paths> {
+        Generator::paths(self)
+    }
+
+    /// Extent of `paths()` as (min_x, min_y, max_x, max_y), or None if empty
+    fn bbox(&mut self) -> PyResult<Option<Bounds>> {
+        Generator::bbox(self)
+    }
 }
 
 impl VoronoiGenerator {
-    /// Find the nearest site to a given point
-    fn nearest_site(&self, x: f64, y: f64, sites: &[(f64, f64)]) -> usize {
+    /// Generate random sites and apply Lloyd's relaxation if requested
+    fn generate_sites(&mut self, should_cancel: Option<&PyObject>) -> PyResult<Vec<(f64, f64)>> {
+        let mut sites: Vec<(f64, f64)> = match self.site_distribution {
+            SiteDistribution::Random => (0..self.num_sites)
+                .map(|_| {
+                    (
+                        self.rng.gen::<f64>() * self.width,
+                        self.rng.gen::<f64>() * self.height,
+                    )
+                })
+                .collect(),
+            SiteDistribution::JitteredGrid => self.jittered_grid_sites(),
+            SiteDistribution::Poisson => self.poisson_sites(),
+        };
+        sites.retain(|&(x, y)| self.inside_clip(x, y));
+
+        self.relax(sites, self.relaxation_iterations, should_cancel)
+    }
+
+    /// Exactly `num_sites` sites, one uniform point per cell of a grid
+    ///
+    /// Rows are chosen to keep cells close to square; each row is split into
+    /// as many equal cells as it has sites, so the count works out exactly.
+    fn jittered_grid_sites(&mut self) -> Vec<(f64, f64)> {
+        let n = self.num_sites;
+        if n == 0 {
+            return Vec::new();
+        }
+        let rows = ((n as f64 * self.height / self.width).sqrt().round() as usize).clamp(1, n);
+        let row_height = self.height / rows as f64;
+
+        let mut sites = Vec::with_capacity(n);
+        for row in 0..rows {
+            let count = n * (row + 1) / rows - n * row / rows;
+            let cell_width = self.width / count as f64;
+            for col in 0..count {
+                sites.push((
+                    (col as f64 + self.rng.gen::<f64>()) * cell_width,
+                    (row as f64 + self.rng.gen::<f64>()) * row_height,
+                ));
+            }
+        }
         sites
-            .iter()
-            .enumerate()
-            .map(|(idx, &(sx, sy))| {
-                let dist_sq = (x - sx).powi(2) + (y - sy).powi(2);
-                (idx, dist_sq)
+    }
+
+    /// Poisson-disk sites (Bridson's algorithm)
+    ///
+    /// The minimum spacing is chosen so the canvas fills up with about
+    /// `num_sites` points; the exact count varies a little with the seed.
+    fn poisson_sites(&mut self) -> Vec<(f64, f64)> {
+        if self.num_sites == 0 || self.width <= 0.0 || self.height <= 0.0 {
+            return Vec::new();
+        }
+        let spacing = (POISSON_DENSITY * self.width * self.height / self.num_sites as f64).sqrt();
+
+        let first = (
+            self.rng.gen::<f64>() * self.width,
+            self.rng.gen::<f64>() * self.height,
+        );
+        let mut grid = SpatialHash::new(spacing);
+        grid.insert(first.0, first.1);
+        let mut sites = vec![first];
+        let mut active = vec![0];
+
+        while !active.is_empty() {
+            let slot = self.rng.gen_range(0..active.len());
+            let (px, py) = sites[active[slot]];
+
+            // Try candidates in the annulus between one and two spacings out
+            let mut placed = false;
+            for _ in 0..POISSON_CANDIDATES {
+                let angle = self.rng.gen::<f64>() * 2.0 * std::f64::consts::PI;
+                let radius = spacing * (1.0 + self.rng.gen::<f64>());
+                let (x, y) = (px + radius * angle.cos(), py + radius * angle.sin());
+                if (0.0..=self.width).contains(&x)
+                    && (0.0..=self.height).contains(&y)
+                    && grid.nearest_within(x, y, spacing).is_none()
+                {
+                    active.push(sites.len());
+                    grid.insert(x, y);
+                    sites.push((x, y));
+                    placed = true;
+                    break;
+                }
+            }
+            if !placed {
+                active.swap_remove(slot);
+            }
+        }
+        sites
+    }
+
+    /// Apply up to `iterations` rounds of Lloyd's relaxation, stopping early
+    /// once `should_cancel` asks to
+    fn relax(
+        &self,
+        mut sites: Vec<(f64, f64)>,
+        iterations: usize,
+        should_cancel: Option<&PyObject>,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        for _ in 0..iterations {
+            if cancel::requested(should_cancel)? {
+                break;
+            }
+            sites = self.lloyd_relaxation(&sites);
+        }
+        Ok(sites)
+    }
+
+    /// Whether a point lies inside the clip polygon (always true without one)
+    #[inline]
+    fn inside_clip(&self, x: f64, y: f64) -> bool {
+        self.clip_polygon
+            .as_ref()
+            .is_none_or(|poly| geometry::point_in_polygon(x, y, poly))
+    }
+
+    /// Clip edges to the clip polygon, if any
+    fn clip_edges(&self, edges: Vec<Segment>) -> Vec<Segment> {
+        let Some(poly) = &self.clip_polygon else {
+            return edges;
+        };
+
+        edges
+            .into_iter()
+            .flat_map(|(a, b)| geometry::clip_polyline_to_polygon(&[a, b], poly, true))
+            .filter_map(|piece| Some((*piece.first()?, *piece.last()?)))
+            .collect()
+    }
+
+    /// Intersect closed cells with the clip polygon, if any, dropping cells
+    /// that fall entirely outside it
+    fn clip_cells(&self, cells: Vec<Vec<(f64, f64)>>) -> Vec<Vec<(f64, f64)>> {
+        let Some(poly) = &self.clip_polygon else {
+            return cells;
+        };
+
+        cells
+            .into_iter()
+            .filter_map(|cell| {
+                let mut clipped = geometry::clip_polygon_to_convex(poly, &cell);
+                if clipped.len() < 3 {
+                    return None;
+                }
+                clipped.push(clipped[0]);
+                Some(clipped)
             })
-            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-            .unwrap()
-            .0
+            .collect()
+    }
+
+    /// Find the nearest site to a given point under the configured metric
+    ///
+    /// Ties go to the lowest site index.
+    #[inline]
+    fn nearest_site(&self, x: f64, y: f64, tree: &KdTree) -> usize {
+        tree.nearest(x, y, |dx, dy| self.metric.compare_key(dx, dy))
+            .expect("at least one site")
     }
 
     /// Perform one iteration of Lloyd's relaxation
     ///
-    /// Moves each site to the centroid of its Voronoi cell
+    /// Moves each site to the centroid of its Voronoi cell, estimated from a
+    /// uniform sample grid with `relaxation_samples` steps per site spacing
+    /// (about `relaxation_samples²` samples per cell). The step never drops
+    /// below 1 unit at the default density; the floor shrinks in proportion
+    /// as `relaxation_samples` grows.
     fn lloyd_relaxation(&self, sites: &[(f64, f64)]) -> Vec<(f64, f64)> {
-        let sample_points = 50; // samples per site for centroid calculation
-
-        let mut new_sites = vec![(0.0, 0.0); sites.len()];
-        let mut counts = vec![0; sites.len()];
+        if sites.is_empty() {
+            return Vec::new();
+        }
+        let sample_points = self.relaxation_samples;
 
         // Sample the space uniformly
+        let tree = KdTree::new(sites);
+        let min_step = DEFAULT_RELAXATION_SAMPLES as f64 / sample_points as f64;
         let step = (self.width.max(self.height)
             / (sample_points as f64 * (sites.len() as f64).sqrt()))
-        .max(1.0);
-        let mut x = 0.0;
-        while x < self.width {
-            let mut y = 0.0;
-            while y < self.height {
-                let nearest = self.nearest_site(x, y, sites);
-                new_sites[nearest].0 += x;
-                new_sites[nearest].1 += y;
-                counts[nearest] += 1;
-                y += step;
-            }
-            x += step;
-        }
+        .max(min_step);
+        // Sample coordinates are accumulated step by step, as the sample
+        // positions have always been
+        let samples = |max: f64| {
+            std::iter::successors(Some(0.0), move |&v| Some(v + step))
+                .take_while(move |&v| v < max)
+                .collect::<Vec<f64>>()
+        };
+        let (xs, ys) = (samples(self.width), samples(self.height));
 
-        // Calculate centroids
-        for i in 0..sites.len() {
-            if counts[i] > 0 {
-                new_sites[i].0 /= counts[i] as f64;
-                new_sites[i].1 /= counts[i] as f64;
+        // Look up the owners column by column in parallel, then sum them in
+        // sample order so the centroids don't depend on the thread count
+        let owners: Vec<Vec<Option<usize>>> = threads::install(|| {
+            xs.par_iter()
+                .map(|&x| {
+                    ys.iter()
+                        .map(|&y| {
+                            self.inside_clip(x, y)
+                                .then(|| self.nearest_site(x, y, &tree))
+                        })
+                        .collect()
+                })
+                .collect()
+        });
 
-                // Keep within bounds
-                new_sites[i].0 = new_sites[i].0.clamp(0.0, self.width);
-                new_sites[i].1 = new_sites[i].1.clamp(0.0, self.height);
-            } else {
-                new_sites[i] = sites[i]; // Keep original if no samples
+        let mut sums = vec![(0.0, 0.0); sites.len()];
+        let mut counts = vec![0usize; sites.len()];
+        for (&x, column) in xs.iter().zip(&owners) {
+            for (&y, &owner) in ys.iter().zip(column) {
+                if let Some(nearest) = owner {
+                    sums[nearest].0 += x;
+                    sums[nearest].1 += y;
+                    counts[nearest] += 1;
+                }
             }
         }
 
-        new_sites
+        // Calculate centroids
+        sites
+            .iter()
+            .zip(sums.iter().zip(&counts))
+            .map(|(&site, (&(sum_x, sum_y), &count))| {
+                if count == 0 {
+                    return site; // Keep original if no samples
+                }
+                // Keep within bounds
+                (
+                    (sum_x / count as f64).clamp(0.0, self.width),
+                    (sum_y / count as f64).clamp(0.0, self.height),
+                )
+            })
+            .collect()
     }
 
-    /// Detect Voronoi edges using sampling approach
+    /// Sample which site owns each point of a grid covering the canvas
     ///
-    /// Samples the space at high resolution and detects boundaries where
-    /// the nearest site changes.
-    fn detect_edges(&self, sites: &[(f64, f64)]) -> Vec<((f64, f64), (f64, f64))> {
-        let mut edges = Vec::new();
+    /// Returns the sample spacing and the grid indexed `[column][row]`.
+    fn owner_grid(&self, sites: &[(f64, f64)]) -> (f64, Vec<Vec<Option<usize>>>) {
         let step = self.width.max(self.height) / self.sampling_resolution as f64;
 
         // Create a grid to store which site owns each cell
         let grid_w = (self.width / step).ceil() as usize + 1;
         let grid_h = (self.height / step).ceil() as usize + 1;
-        let mut grid = vec![vec![None; grid_h]; grid_w];
 
-        // Fill grid with nearest site indices
+        // Fill grid with nearest site indices, one column per rayon task
+        let tree = KdTree::new(sites);
+        let grid = threads::install(|| {
+            (0..grid_w)
+                .into_par_iter()
+                .map(|i| {
+                    let x = (i as f64 * step).min(self.width);
+                    (0..grid_h)
+                        .map(|j| {
+                            let y = (j as f64 * step).min(self.height);
+                            Some(self.nearest_site(x, y, &tree))
+                        })
+                        .collect()
+                })
+                .collect()
+        });
+
+        (step, grid)
+    }
+
+    /// Site pairs whose cells touch, found by scanning the sample grid for
+    /// neighboring samples owned by different sites
+    ///
+    /// With a clip polygon, only boundaries inside it count.
+    fn detect_adjacency(&self, sites: &[(f64, f64)]) -> Vec<(usize, usize)> {
+        if sites.is_empty() {
+            return Vec::new();
+        }
+
+        let (step, grid) = self.owner_grid(sites);
+        let (grid_w, grid_h) = (grid.len(), grid[0].len());
+        let sample = |i: usize, j: usize| {
+            let x = (i as f64 * step).min(self.width);
+            let y = (j as f64 * step).min(self.height);
+            self.inside_clip(x, y)
+        };
+
+        let mut pairs = BTreeSet::new();
         for i in 0..grid_w {
             for j in 0..grid_h {
-                let x = (i as f64 * step).min(self.width);
-                let y = (j as f64 * step).min(self.height);
-                grid[i][j] = Some(self.nearest_site(x, y, sites));
+                let current = grid[i][j];
+                for (ni, nj) in [(i + 1, j), (i, j + 1)] {
+                    if ni >= grid_w || nj >= grid_h {
+                        continue;
+                    }
+                    let (Some(a), Some(b)) = (current, grid[ni][nj]) else {
+                        continue;
+                    };
+                    if a != b && sample(i, j) && sample(ni, nj) {
+                        pairs.insert((a.min(b), a.max(b)));
+                    }
+                }
             }
         }
 
-        // Detect edges by looking for neighboring cells with different sites
-        let mut edge_set = HashSet::new();
+        pairs.into_iter().collect()
+    }
+
+    /// Detect Voronoi edges using sampling approach
+    ///
+    /// Samples the space at high resolution and detects boundaries where
+    /// the nearest site changes.
+    fn detect_edges(&self, sites: &[(f64, f64)]) -> Vec<Segment> {
+        if sites.is_empty() {
+            return Vec::new();
+        }
+        let mut edges = Vec::new();
+        let (step, grid) = self.owner_grid(sites);
+        let (grid_w, grid_h) = (grid.len(), grid[0].len());
 
+        // Detect edges by looking for neighboring cells with different sites
         for i in 0..grid_w - 1 {
             for j in 0..grid_h - 1 {
                 let current = grid[i][j].unwrap();
@@ -221,12 +768,7 @@ impl VoronoiGenerator {
                             } else {
                                 ((x, y2), (x, y1))
                             };
-                            edge_set.insert((
-                                (edge.0 .0 * 1000.0) as i64,
-                                (edge.0 .1 * 1000.0) as i64,
-                                (edge.1 .0 * 1000.0) as i64,
-                                (edge.1 .1 * 1000.0) as i64,
-                            ));
+                            edges.push(edge);
                         }
                     }
                 }
@@ -248,26 +790,236 @@ impl VoronoiGenerator {
                             } else {
                                 ((x2, y), (x1, y))
                             };
-                            edge_set.insert((
-                                (edge.0 .0 * 1000.0) as i64,
-                                (edge.0 .1 * 1000.0) as i64,
-                                (edge.1 .0 * 1000.0) as i64,
-                                (edge.1 .1 * 1000.0) as i64,
-                            ));
+                            edges.push(edge);
                         }
                     }
                 }
             }
         }
 
-        // Convert back to float edges
-        for &(x1i, y1i, x2i, y2i) in edge_set.iter() {
-            edges.push((
-                (x1i as f64 / 1000.0, y1i as f64 / 1000.0),
-                (x2i as f64 / 1000.0, y2i as f64 / 1000.0),
-            ));
+        // Exact matching keeps every edge at full precision
+        optimize::unique_segments(edges, 0.0)
+    }
+
+    /// Exact Voronoi cell of one site, clipped to the canvas
+    ///
+    /// Starts from the canvas rectangle and cuts it with the bisector of each
+    /// Delaunay neighbor. Each vertex carries the label of the edge leaving it:
+    /// the neighbor whose bisector formed that edge, or `None` on the canvas
+    /// border.
+    fn exact_cell(
+        &self,
+        site: usize,
+        sites: &[(f64, f64)],
+        neighbors: &[usize],
+    ) -> Vec<((f64, f64), Option<usize>)> {
+        let mut cell = vec![
+            ((0.0, 0.0), None),
+            ((self.width, 0.0), None),
+            ((self.width, self.height), None),
+            ((0.0, self.height), None),
+        ];
+
+        let (sx, sy) = sites[site];
+        for &other in neighbors {
+            let (ox, oy) = sites[other];
+            let (nx, ny) = (ox - sx, oy - sy);
+            if nx * nx + ny * ny < 1e-18 {
+                continue; // Coincident sites have no bisector
+            }
+            let (mx, my) = ((sx + ox) / 2.0, (sy + oy) / 2.0);
+
+            // Signed distance along the normal; <= 0 is closer to `site`
+            let side = |(x, y): (f64, f64)| (x - mx) * nx + (y - my) * ny;
+
+            let mut clipped = Vec::with_capacity(cell.len() + 1);
+            for k in 0..cell.len() {
+                let (p, label) = cell[k];
+                let (q, _) = cell[(k + 1) % cell.len()];
+                let (dp, dq) = (side(p), side(q));
+
+                if dp <= 0.0 {
+                    clipped.push((p, label));
+                }
+                if (dp <= 0.0) != (dq <= 0.0) {
+                    let t = dp / (dp - dq);
+                    let x = (p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t);
+                    // Leaving the half-plane starts an edge along the bisector
+                    clipped.push((x, if dp <= 0.0 { Some(other) } else { label }));
+                }
+            }
+
+            cell = clipped;
+            if cell.is_empty() {
+                break;
+            }
+        }
+
+        cell
+    }
+
+    /// Exact closed cell polygons for every site
+    ///
+    /// Coincident sites that never entered the triangulation are skipped.
+    fn exact_cells(&self, sites: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+        let neighbors = delaunay::neighbors(sites);
+
+        neighbors
+            .iter()
+            .enumerate()
+            .filter(|(_, site_neighbors)| !site_neighbors.is_empty() || sites.len() == 1)
+            .filter_map(|(site, site_neighbors)| {
+                let mut cell: Vec<(f64, f64)> = self
+                    .exact_cell(site, sites, site_neighbors)
+                    .into_iter()
+                    .map(|(p, _)| p)
+                    .collect();
+                let first = *cell.first()?;
+                cell.push(first);
+                Some(cell)
+            })
+            .collect()
+    }
+
+    /// Trace a cell boundary for an arbitrary metric
+    ///
+    /// Cells under any norm are star-shaped around their site, so each ray
+    /// from the site leaves the cell exactly once; that crossing is found by
+    /// bisection, stopping early at the canvas edge.
+    fn traced_cell(
+        &self,
+        site: usize,
+        sites: &[(f64, f64)],
+        angular_samples: usize,
+    ) -> Vec<(f64, f64)> {
+        let (sx, sy) = sites[site];
+        let owns = |x: f64, y: f64| {
+            let key = self.metric.compare_key(x - sx, y - sy);
+            sites
+                .iter()
+                .enumerate()
+                .all(|(i, &(ox, oy))| i == site || key <= self.metric.compare_key(x - ox, y - oy))
+        };
+
+        let mut cell = Vec::with_capacity(angular_samples + 1);
+        for i in 0..angular_samples {
+            let theta = (i as f64 / angular_samples as f64) * 2.0 * std::f64::consts::PI;
+            let (dx, dy) = (theta.cos(), theta.sin());
+
+            // Distance to the canvas edge along the ray
+            let tx = if dx > 0.0 {
+                (self.width - sx) / dx
+            } else if dx < 0.0 {
+                -sx / dx
+            } else {
+                f64::INFINITY
+            };
+            let ty = if dy > 0.0 {
+                (self.height - sy) / dy
+            } else if dy < 0.0 {
+                -sy / dy
+            } else {
+                f64::INFINITY
+            };
+            let t_max = tx.min(ty).max(0.0);
+
+            let t = if owns(sx + dx * t_max, sy + dy * t_max) {
+                t_max
+            } else {
+                let (mut lo, mut hi) = (0.0, t_max);
+                for _ in 0..40 {
+                    let mid = (lo + hi) / 2.0;
+                    if owns(sx + dx * mid, sy + dy * mid) {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                lo
+            };
+
+            cell.push((sx + dx * t, sy + dy * t));
+        }
+
+        if let Some(&first) = cell.first() {
+            cell.push(first);
+        }
+
+        cell
+    }
+
+    /// Exact Voronoi edges from the Delaunay dual
+    ///
+    /// Each bisector edge is shared by two cells and emitted once, from the
+    /// lower-indexed site.
+    fn exact_edges(&self, sites: &[(f64, f64)]) -> Vec<Segment> {
+        let neighbors = delaunay::neighbors(sites);
+        let mut edges = Vec::new();
+
+        for (site, site_neighbors) in neighbors.iter().enumerate() {
+            if site_neighbors.is_empty() {
+                continue; // Duplicate site that never entered the triangulation
+            }
+
+            let cell = self.exact_cell(site, sites, site_neighbors);
+            for k in 0..cell.len() {
+                let (p, label) = cell[k];
+                let (q, _) = cell[(k + 1) % cell.len()];
+
+                if let Some(other) = label {
+                    let length_sq = (q.0 - p.0).powi(2) + (q.1 - p.1).powi(2);
+                    if other > site && length_sq > 1e-18 {
+                        edges.push((p, q));
+                    }
+                }
+            }
         }
 
         edges
     }
 }
+
+impl Generator for VoronoiGenerator {
+    fn paths(&mut self) -> PyResult<Paths> {
+        Python::with_gil(|py| self.generate(py, false, None))
+            .map(|(_, edges)| Paths::from_segments(edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_resolution_edges_are_not_merged_or_rounded() {
+        // Two sites split a thin strip at x = 0.5; at a sampling step of
+        // 0.00025 every row contributes its own vertical fragment
+        let generator = VoronoiGenerator {
+            width: 1.0,
+            height: 0.01,
+            num_sites: 2,
+            relaxation_iterations: 0,
+            relaxation_samples: DEFAULT_RELAXATION_SAMPLES,
+            clip_to_bounds: true,
+            sampling_resolution: 4000,
+            metric: Metric::Euclidean,
+            clip_polygon: None,
+            site_distribution: SiteDistribution::Random,
+            seed: 0,
+            rng: ChaCha8Rng::seed_from_u64(0),
+        };
+        let step = 1.0 / 4000.0;
+
+        let edges = generator.detect_edges(&[(0.25, 0.005), (0.75, 0.005)]);
+
+        assert_eq!(edges.len(), 40);
+        for &((x1, y1), (x2, y2)) in &edges {
+            assert_eq!(x1, 2000.5 * step);
+            assert_eq!(x2, 2000.5 * step);
+            assert!((y2 - y1 - step).abs() < 1e-12);
+        }
+
+        let total: f64 = edges.iter().map(|&((_, y1), (_, y2))| y2 - y1).sum();
+        assert!((total - 0.01).abs() < 1e-12);
+    }
+}
@@ -1,6 +1,6 @@
 """Dendrite and branching pattern generator using DLA (Rust-accelerated)."""
 
-from typing import List, Tuple, Optional
+from typing import Callable, List, Tuple, Optional
 from ..svg_exporter import SVGCanvas
 
 try:
@@ -32,7 +32,8 @@ class DendritePattern:
         min_move_distance: float = 2.0,
         seed_points: Optional[List[Tuple[float, float]]] = None,
         branching_style: str = "radial",  # radial, vertical, horizontal
-        seed: Optional[int] = None
+        seed: Optional[int] = None,
+        obstacles: Optional[List[Tuple[float, float, float]]] = None
     ):
         """
         Initialize the dendrite pattern generator.
@@ -46,6 +47,12 @@ class DendritePattern:
             seed_points: Initial seed points (uses center if None)
             branching_style: Style of branching (radial, vertical, horizontal)
             seed: Random seed for reproducibility
+            obstacles: Exclusion circles as (cx, cy, r) that the aggregate grows
+                around, for carving holes or reserving space; particles never
+                stick inside one and start over if they wander in
+
+        Raises:
+            ValueError: If a seed point lies inside an obstacle
         """
         self.width = width
         self.height = height
@@ -53,7 +60,7 @@ class DendritePattern:
         self.attraction_distance = attraction_distance
         self.min_move_distance = min_move_distance
         self.branching_style = branching_style
-        self.seed = seed
+        self.obstacles = obstacles
 
         self.tree = []
         self.lines = []
@@ -67,17 +74,86 @@ class DendritePattern:
             min_move_distance=min_move_distance,
             seed_points=seed_points,
             branching_style=branching_style,
-            seed=seed
+            seed=seed,
+            obstacles=obstacles
         )
+        # The seed in use, drawn at random when none was given
+        self.seed = self._generator.seed
+
+    @staticmethod
+    def seeds_on_circle(
+        cx: float,
+        cy: float,
+        r: float,
+        n: int = 36
+    ) -> List[Tuple[float, float]]:
+        """
+        Seed points evenly spaced around a circle, for seed_points.
+
+        Args:
+            cx: Circle center x
+            cy: Circle center y
+            r: Circle radius
+            n: Number of points (the first at angle 0)
+        """
+        return _RustDendriteGenerator.seeds_on_circle(cx, cy, r, n)
+
+    @staticmethod
+    def seeds_on_line(
+        p0: Tuple[float, float],
+        p1: Tuple[float, float],
+        n: int = 20
+    ) -> List[Tuple[float, float]]:
+        """
+        Seed points evenly spaced from p0 to p1 (both included), for seed_points.
 
-    def generate(self, max_attempts: int = 1000):
+        Args:
+            p0: Start of the line
+            p1: End of the line
+            n: Number of points
+        """
+        return _RustDendriteGenerator.seeds_on_line(p0, p1, n)
+
+    def generate(
+        self,
+        max_attempts: int = 1000,
+        should_cancel: Optional[Callable[[], bool]] = None
+    ):
         """
         Generate the dendrite pattern.
 
         Args:
             max_attempts: Maximum random walk attempts per particle
+            should_cancel: Optional callable polled every 100 particles; returning
+                True stops growth early and keeps the tree built so far
         """
-        points, lines = self._generator.generate(max_attempts)
+        points, lines = self._generator.generate(max_attempts, should_cancel)
+        self.tree = points
+        self.lines = lines
+
+    def generate_from(
+        self,
+        points: List[Tuple[float, float]],
+        lines: List[Tuple[Tuple[float, float], Tuple[float, float]]],
+        additional_particles: int,
+        max_attempts: int = 1000,
+        should_cancel: Optional[Callable[[], bool]] = None
+    ):
+        """
+        Continue growing an existing dendrite instead of starting over.
+
+        Args:
+            points: Nodes of the existing tree (e.g. from get_points()); pass
+                several trees' points and lines together to merge clusters
+            lines: Branches of the existing tree (e.g. from get_lines())
+            additional_particles: Number of particles to add
+            max_attempts: Maximum random walk attempts per particle
+            should_cancel: Optional callable polled every 100 particles; returning
+                True stops growth early and keeps the tree built so far
+        """
+        points, lines = self._generator.generate_from(
+            points, lines, additional_particles, max_attempts, should_cancel
+        )
         self.tree = points
         self.lines = lines
 
@@ -46,7 +46,6 @@ class FlowFieldPattern:
         self.height = height
         self.field_type = field_type
         self.scale = scale
-        self.seed = seed
 
         # Initialize Rust generator
         self._generator = _RustFlowFieldGenerator(
@@ -56,15 +55,23 @@ class FlowFieldPattern:
             scale=scale,
             seed=seed
         )
+        # The seed in use, drawn at random when none was given
+        self.seed = self._generator.seed
 
         self.paths = []
+        self.magnitudes = []
 
     def generate_streamlines(
         self,
         num_lines: int = 50,
         steps: int = 100,
         step_size: float = 1.0,
-        parallel: bool = True
+        parallel: bool = True,
+        stop_on_self_intersection: bool = False,
+        boundary: str = "stop",
+        length_variation: float = 0.0,
+        time: Optional[float] = None,
+        return_magnitude: bool = False
     ):
         """
         Generate streamlines by tracing particles through the flow field.
@@ -74,20 +81,44 @@ class FlowFieldPattern:
             steps: Number of steps per streamline
             step_size: Distance to move at each step
             parallel: Use parallel generation (faster on multi-core systems)
+            stop_on_self_intersection: End each line where it would cross itself
+            boundary: At the canvas edge, "stop", "reflect" back inside, or
+                "wrap" to the opposite edge (continuing as a new path)
+            length_variation: 0-1; shortens each line's steps by a seeded random
+                factor down to (1 - length_variation) for a mix of long and short lines
+            time: Sample the noise field in 3D at this depth; sweep it across
+                frames (e.g. by 0.02 per frame) for a smoothly evolving flow.
+                None uses the static 2D field. Ignored by non-noise fields.
+            return_magnitude: Also store each path's mean field magnitude in
+                self.magnitudes, so list(zip(self.paths, self.magnitudes)) can
+                go to magnitude_to_passes
         """
-        self.paths = self._generator.generate_streamlines(
+        result = self._generator.generate_streamlines(
             num_lines=num_lines,
             steps=steps,
             step_size=step_size,
-            parallel=parallel
+            parallel=parallel,
+            stop_on_self_intersection=stop_on_self_intersection,
+            boundary=boundary,
+            length_variation=length_variation,
+            time=time,
+            return_magnitude=return_magnitude
         )
+        if return_magnitude:
+            self.paths = [path for path, _ in result]
+            self.magnitudes = [magnitude for _, magnitude in result]
+        else:
+            self.paths = result
+            self.magnitudes = []
 
     def generate_curl_noise_lines(
         self,
         num_lines: int = 50,
         steps: int = 100,
         step_size: float = 1.0,
-        parallel: bool = True
+        parallel: bool = True,
+        stop_on_self_intersection: bool = False,
+        boundary: str = "stop"
     ):
         """
         Generate divergence-free flow lines using curl noise.
@@ -97,12 +128,17 @@ class FlowFieldPattern:
             steps: Number of steps per line
             step_size: Distance to move at each step
             parallel: Use parallel generation (faster on multi-core systems)
+            stop_on_self_intersection: End each line where it would cross itself
+            boundary: At the canvas edge, "stop", "reflect" back inside, or
+                "wrap" to the opposite edge (continuing as a new path)
         """
         self.paths = self._generator.generate_curl_noise_lines(
             num_lines=num_lines,
             steps=steps,
             step_size=step_size,
-            parallel=parallel
+            parallel=parallel,
+            stop_on_self_intersection=stop_on_self_intersection,
+            boundary=boundary
         )
 
     def generate_grid_visualization(
@@ -1,10 +1,11 @@
-"""Grid pattern generator for square and hexagonal grids (Rust-accelerated)."""
+"""Grid pattern generator for square, hexagonal, triangular and radial grids (Rust-accelerated)."""
 
-from typing import List, Tuple, Optional, Callable
+from typing import List, Tuple, Optional, Callable, Union
 from ..svg_exporter import SVGCanvas
 
 try:
     from axiart.axiart_core import GridGenerator as _RustGridGenerator
+    from axiart.axiart_core import GridType
 except ImportError as e:
     raise ImportError(
         "Rust acceleration library not found. Please build it with:\n"
@@ -17,7 +18,7 @@ class GridPattern:
     """
     Generate geometric grid structures (Rust-accelerated).
 
-    Supports square grids, hexagonal grids, and radial distortions.
+    Supports square, hexagonal, triangular and radial grids, and radial distortions.
 
     Performance: 8-12M points/sec (pure Rust implementation)
     """
@@ -26,7 +27,7 @@ class GridPattern:
         self,
         width: float = 297,
         height: float = 210,
-        grid_type: str = "square"  # square, hexagonal
+        grid_type: Union[str, GridType] = "square"  # square, hexagonal, triangular
     ):
         """
         Initialize the grid pattern generator.
@@ -34,21 +35,35 @@ class GridPattern:
         Args:
             width: Canvas width
             height: Canvas height
-            grid_type: Type of grid (square, hexagonal)
+            grid_type: Type of grid used by generate(), as a GridType or its name
+                (square, hexagonal, triangular)
         """
         self.width = width
         self.height = height
         self.grid_type = grid_type
         self.lines = []
         self.cells = []
+        self.nodes = []
+        self.edges = []
+        self.seed = None
 
         # Initialize Rust generator
         self._generator = _RustGridGenerator(width=width, height=height)
 
+    def generate(self, cell_size: float = 10):
+        """
+        Generate a grid of the type chosen at construction.
+
+        Args:
+            cell_size: Size of grid cells
+        """
+        self.lines = self._generator.generate(grid_type=self.grid_type, cell_size=cell_size)
+
     def generate_square_grid(
         self,
         cell_size: float = 10,
-        jitter: float = 0
+        jitter: float = 0,
+        seed: Optional[int] = None
     ):
         """
         Generate a square grid.
@@ -56,23 +71,133 @@ class GridPattern:
         Args:
             cell_size: Size of grid cells
             jitter: Random jitter amount (0 = perfect grid)
+            seed: Random seed for reproducible jitter (the one in use, including
+                one drawn from entropy, is stored in self.seed; None without jitter)
         """
         self.lines = self._generator.generate_square_grid(
             cell_size=cell_size,
-            jitter=jitter
+            jitter=jitter,
+            seed=seed
         )
+        self.seed = self._generator.last_seed if jitter > 0 else None
 
     def generate_hexagonal_grid(
         self,
-        cell_size: float = 10
+        cell_size: float = 10,
+        jitter: float = 0,
+        seed: Optional[int] = None,
+        warp_strength: float = 0,
+        warp_scale: float = 50.0,
+        warp_seed: int = 0
     ):
         """
         Generate a hexagonal grid.
 
+        Hexagons keep their shape while their centers are perturbed, for an
+        organic, hand-drawn honeycomb.
+
         Args:
             cell_size: Size of hexagonal cells
+            jitter: Random offset of each center, up to jitter/2 per axis
+                (0 = perfect grid)
+            seed: Random seed for reproducible jitter (the one in use, including
+                one drawn from entropy, is stored in self.seed; None without jitter)
+            warp_strength: Maximum smooth Perlin offset of each center, so
+                neighboring cells drift together (0 = no warp)
+            warp_scale: Noise feature size (larger = broader warp)
+            warp_seed: Noise seed for the warp
+        """
+        self.lines = self._generator.generate_hexagonal_grid(
+            cell_size=cell_size,
+            jitter=jitter,
+            seed=seed,
+            warp_strength=warp_strength,
+            warp_scale=warp_scale,
+            warp_seed=warp_seed
+        )
+        self.seed = self._generator.last_seed if jitter > 0 else None
+
+    def generate_square_cells(
+        self,
+        cell_size: float = 10
+    ):
+        """
+        Generate square grid cells as closed polygons.
+
+        Results are stored in self.cells, ready for filling or hatching.
+
+        Args:
+            cell_size: Size of grid cells
+        """
+        self.cells = self._generator.generate_square_cells(cell_size=cell_size)
+
+    def generate_square_graph(
+        self,
+        cell_size: float = 10
+    ):
+        """
+        Generate the square grid as a graph of intersections.
+
+        Results are stored in self.nodes (every line crossing, row-major) and
+        self.edges (node index pairs, one per cell side), ready for spanning
+        trees, random walks or other graph algorithms.
+
+        Args:
+            cell_size: Size of grid cells
+        """
+        self.nodes, self.edges = self._generator.generate_square_graph(cell_size=cell_size)
+
+    def generate_hexagonal_cells(
+        self,
+        cell_size: float = 10
+    ):
+        """
+        Generate tessellating hexagonal cells as closed polygons.
+
+        Results are stored in self.cells, ready for filling or hatching.
+
+        Args:
+            cell_size: Flat-to-flat width of each hexagon
+        """
+        self.cells = self._generator.generate_hexagonal_cells(cell_size=cell_size)
+
+    def generate_triangular_grid(
+        self,
+        cell_size: float = 10
+    ):
         """
-        self.lines = self._generator.generate_hexagonal_grid(cell_size=cell_size)
+        Generate a triangular grid (lines at 0, 60 and 120 degrees).
+
+        Args:
+            cell_size: Side length of the triangles
+        """
+        self.lines = self._generator.generate_triangular_grid(cell_size=cell_size)
+
+    def generate_radial_grid(
+        self,
+        num_rings: int = 10,
+        num_spokes: int = 12,
+        center: Optional[Tuple[float, float]] = None,
+        max_radius: Optional[float] = None,
+        points_per_ring: int = 100
+    ):
+        """
+        Generate a polar grid of concentric rings and radial spokes.
+
+        Args:
+            num_rings: Number of concentric circles
+            num_spokes: Number of spokes from the center
+            center: Center of the grid (uses canvas center if None)
+            max_radius: Radius of the outermost ring (auto-calculated if None)
+            points_per_ring: Resolution of each ring
+        """
+        self.lines = self._generator.generate_radial_grid(
+            num_rings=num_rings,
+            num_spokes=num_spokes,
+            center=center,
+            max_radius=max_radius,
+            points_per_ring=points_per_ring
+        )
 
     def apply_radial_distortion(
         self,
@@ -95,6 +220,61 @@ class GridPattern:
             strength=strength
         )
 
+    def apply_twist_distortion(
+        self,
+        center: Optional[Tuple[float, float]] = None,
+        strength: float = 0.01
+    ):
+        """
+        Apply a vortex (twist) distortion to existing grid lines.
+
+        Each vertex is rotated about the center by strength * distance radians.
+
+        Args:
+            center: Center of the twist (uses canvas center if None)
+            strength: Rotation in radians per unit distance
+        """
+        if not self.lines:
+            raise ValueError("No grid lines to distort. Generate a grid first.")
+
+        self.lines = self._generator.apply_twist_distortion(
+            lines=self.lines,
+            center=center,
+            strength=strength
+        )
+
+    def apply_noise_distortion(
+        self,
+        scale: float = 50.0,
+        strength: float = 5.0,
+        seed: int = 0
+    ):
+        """
+        Apply a flowing Perlin noise warp to existing grid lines.
+
+        Args:
+            scale: Noise feature size (larger = broader warp)
+            strength: Maximum vertex offset
+            seed: Noise seed for reproducibility
+        """
+        if not self.lines:
+            raise ValueError("No grid lines to distort. Generate a grid first.")
+
+        self.lines = self._generator.apply_noise_distortion(
+            lines=self.lines,
+            scale=scale,
+            strength=strength,
+            seed=seed
+        )
+
+    def clip_to_bounds(self):
+        """
+        Clip existing grid lines to the canvas, splitting lines that leave and re-enter.
+
+        Useful after distortions push lines past the paper edge.
+        """
+        self.lines = self._generator.clip_to_bounds(self.lines)
+
     def draw(self, canvas: SVGCanvas, layer: str):
         """
         Draw the grid pattern on the canvas.
@@ -109,7 +289,8 @@ class GridPattern:
 
         for cell in self.cells:
             if len(cell) > 1:
-                canvas.add_polygon(cell, layer=layer)
+                # Cells are already closed (first point repeated at the end)
+                canvas.add_polyline(cell, layer=layer)
 
     def get_lines(self) -> List[List[Tuple[float, float]]]:
         """Get all grid lines."""
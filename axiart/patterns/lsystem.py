@@ -60,6 +60,8 @@ class LSystemPattern:
         self.iterations = iterations
 
         self.lines = []
+        self.colors = []
+        self.frames = []
 
         # Initialize Rust generator
         self._generator = _RustLSystemGenerator(
@@ -141,15 +143,42 @@ class LSystemPattern:
 
         return instance
 
-    def generate(self):
+    def generate(self, remove_overlaps: bool = False):
         """
         Generate the L-System pattern.
 
         Expands the L-System string and interprets it as turtle graphics.
+
+        Args:
+            remove_overlaps: Merge lines that retrace each other along the same
+                line (as branches returning along their stem do) so no stroke
+                is inked twice
         """
-        lines = self._generator.generate()
+        lines = self._generator.generate(remove_overlaps=remove_overlaps)
         self.lines = lines
 
+    def generate_with_color(self):
+        """
+        Generate the L-System pattern with a pen color index per line.
+
+        Each ' in the expanded string advances the color index (saved and
+        restored by [ and ]). Stores the lines in self.lines and their color
+        indices in self.colors, for splitting a plot across pens.
+        """
+        colored = self._generator.generate_with_color()
+        self.lines = [(start, end) for start, end, _ in colored]
+        self.colors = [color for _, _, color in colored]
+
+    def generate_iterations(self):
+        """
+        Generate the pattern after every expansion step, for build-up animations.
+
+        Stores one line list per iteration count (0 = bare axiom, up to
+        iterations) in self.frames, and the final frame in self.lines.
+        """
+        self.frames = self._generator.generate_iterations()
+        self.lines = self.frames[-1]
+
     def draw(self, canvas: SVGCanvas, layer: str):
         """
         Draw the L-System pattern on the canvas.
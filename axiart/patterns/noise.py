@@ -51,7 +51,6 @@ class NoisePattern:
         self.octaves = octaves
         self.persistence = persistence
         self.lacunarity = lacunarity
-        self.seed = seed
 
         # Initialize Rust generator
         self._generator = _RustNoisePatternGenerator(
@@ -63,9 +62,12 @@ class NoisePattern:
             lacunarity=lacunarity,
             seed=seed
         )
+        # The seed in use, drawn at random when none was given
+        self.seed = self._generator.seed
 
         self.lines = []
         self.points = []
+        self.radii = []
 
     def generate_contour_lines(
         self,
@@ -90,6 +92,35 @@ class NoisePattern:
             max_value=max_value
         )
 
+    def generate_contour_paths(
+        self,
+        num_levels: int = 20,
+        resolution: float = 2.0,
+        min_value: float = -1.0,
+        max_value: float = 1.0,
+        smooth: int = 0
+    ):
+        """
+        Generate contour lines joined into continuous polylines.
+
+        Closed contours are returned as closed loops. Fewer, longer strokes
+        plot faster than the raw segments of generate_contour_lines.
+
+        Args:
+            num_levels: Number of contour levels
+            resolution: Grid resolution (smaller = more detail)
+            min_value: Minimum noise value
+            max_value: Maximum noise value
+            smooth: Catmull-Rom smoothing passes (0 keeps the polygonal contours)
+        """
+        self.lines = self._generator.generate_contour_paths(
+            num_levels=num_levels,
+            resolution=resolution,
+            min_value=min_value,
+            max_value=max_value,
+            smooth=smooth
+        )
+
     def generate_stippling(
         self,
         num_points: int = 5000,
@@ -106,6 +137,7 @@ class NoisePattern:
             threshold: Noise threshold for point placement
             parallel: Use parallel generation
         """
+        self.radii = []
         self.points = self._generator.generate_stippling(
             num_points=num_points,
             density_map=density_map,
@@ -128,22 +160,26 @@ class NoisePattern:
             pattern_type: Type of pattern (squares, circles, hatching)
 
         Returns:
-            Tuple of (paths, points) where paths are lines and points are centers
+            Tuple of (paths, points) where paths are lines and points are centers.
+            For circles, the matching dot radii (scaled by noise intensity) are
+            stored in `self.radii` and drawn as proportional dots.
         """
-        paths, points = self._generator.generate_cellular_texture(
+        paths, points, radii = self._generator.generate_cellular_texture(
             cell_size=cell_size,
             threshold=threshold,
             pattern_type=pattern_type
         )
         self.lines = paths
         self.points = points
+        self.radii = radii
         return paths, points
 
     def generate_hatching(
         self,
         spacing: float = 5.0,
         line_length: float = 10.0,
-        threshold: float = 0.0
+        threshold: float = 0.0,
+        orientation: str = "value"
     ):
         """
         Generate hatching lines based on noise gradients.
@@ -152,11 +188,15 @@ class NoisePattern:
             spacing: Space between hatch lines
             line_length: Length of hatch lines
             threshold: Noise threshold for hatching
+            orientation: "value" angles strokes by the noise value; "gradient"
+                lays them along the contours (perpendicular to the analytic
+                noise gradient) for convincing shaded relief
         """
         self.lines = self._generator.generate_hatching(
             spacing=spacing,
             line_length=line_length,
-            threshold=threshold
+            threshold=threshold,
+            orientation=orientation
         )
 
     def draw(self, canvas: SVGCanvas, layer: str):
@@ -173,7 +213,10 @@ class NoisePattern:
                 canvas.add_polyline(line, layer=layer)
 
         # Draw points
-        if self.points:
+        if self.points and self.radii:
+            for center, radius in zip(self.points, self.radii):
+                canvas.add_circle(center, radius, layer=layer)
+        elif self.points:
             canvas.add_points(self.points, layer=layer)
 
     def get_lines(self) -> List[List[Tuple[float, float]]]:
@@ -1,10 +1,11 @@
 """Spiral and concentric circle pattern generator (Rust-accelerated)."""
 
-from typing import List, Tuple, Optional
+from typing import List, Tuple, Optional, Union
 from ..svg_exporter import SVGCanvas
 
 try:
     from axiart.axiart_core import SpiralGenerator as _RustSpiralGenerator
+    from axiart.axiart_core import SpiralType
 except ImportError as e:
     raise ImportError(
         "Rust acceleration library not found. Please build it with:\n"
@@ -31,7 +32,7 @@ class SpiralPattern:
         center: Optional[Tuple[float, float]] = None,
         num_revolutions: int = 20,
         points_per_revolution: int = 100,
-        spiral_type: str = "archimedean"  # archimedean, logarithmic, concentric
+        spiral_type: Union[str, SpiralType] = "archimedean"  # archimedean, logarithmic, concentric
     ):
         """
         Initialize the spiral pattern generator.
@@ -42,7 +43,8 @@ class SpiralPattern:
             center: Center point (uses canvas center if None)
             num_revolutions: Number of spiral revolutions
             points_per_revolution: Points per revolution (resolution)
-            spiral_type: Type of spiral (archimedean, logarithmic, concentric)
+            spiral_type: Type of spiral, as a SpiralType or its name
+                (archimedean, logarithmic, concentric)
         """
         self.width = width
         self.height = height
@@ -70,7 +72,14 @@ class SpiralPattern:
         rotation_offset: float = 0,
         growth_factor: float = 1.0,
         num_spirals: int = 1,
-        angular_offset: float = 0
+        angular_offset: float = 0,
+        uniform_arc_length: bool = False,
+        noise_displacement: float = 0.0,
+        noise_scale: float = 20.0,
+        noise_seed: int = 0,
+        concentric_connected: bool = False,
+        clockwise: bool = False,
+        inward: bool = False
     ):
         """
         Generate spiral pattern.
@@ -82,6 +91,16 @@ class SpiralPattern:
             growth_factor: Growth rate for spirals
             num_spirals: Number of parallel spirals
             angular_offset: Angular offset between spirals
+            uniform_arc_length: Space points evenly along the curve instead of by angle
+            noise_displacement: Amplitude of Perlin wobble along the outward normal
+            noise_scale: Feature size of the wobble noise
+            noise_seed: Seed for the wobble noise
+            concentric_connected: For the concentric type, draw each ring as a full
+                circle joined to the next by a radial jog, instead of the default
+                staircase where rings step outward along a slanted chord
+            clockwise: Wind the other way (decreasing angle) from the same start
+                point instead of counterclockwise
+            inward: Reverse each spiral so it plots from the rim to the center
         """
         self.spirals = self._generator.generate(
             start_radius=start_radius,
@@ -89,9 +108,66 @@ class SpiralPattern:
             rotation_offset=rotation_offset,
             growth_factor=growth_factor,
             num_spirals=num_spirals,
-            angular_offset=angular_offset
+            angular_offset=angular_offset,
+            uniform_arc_length=uniform_arc_length,
+            noise_displacement=noise_displacement,
+            noise_scale=noise_scale,
+            noise_seed=noise_seed,
+            concentric_connected=concentric_connected,
+            clockwise=clockwise,
+            inward=inward
         )
 
+    def generate_with_width(
+        self,
+        start_width: float = 0.1,
+        end_width: float = 1.0,
+        start_radius: float = 5,
+        end_radius: Optional[float] = None,
+        rotation_offset: float = 0,
+        growth_factor: float = 1.0,
+        num_spirals: int = 1,
+        angular_offset: float = 0,
+        uniform_arc_length: bool = False,
+        noise_displacement: float = 0.0,
+        noise_scale: float = 20.0,
+        noise_seed: int = 0,
+        concentric_connected: bool = False,
+        clockwise: bool = False,
+        inward: bool = False
+    ):
+        """
+        Generate spiral pattern with a stroke width per point.
+
+        Same spirals as generate, e.g. for a variable-pressure plotter. Stores
+        the points in self.spirals and the matching widths in self.widths.
+
+        Args:
+            start_width: Stroke width at the center
+            end_width: Stroke width at the rim; widths are interpolated
+                linearly by distance along the curve
+            The remaining arguments are as for generate.
+        """
+        tapered = self._generator.generate_with_width(
+            start_width=start_width,
+            end_width=end_width,
+            start_radius=start_radius,
+            end_radius=end_radius,
+            rotation_offset=rotation_offset,
+            growth_factor=growth_factor,
+            num_spirals=num_spirals,
+            angular_offset=angular_offset,
+            uniform_arc_length=uniform_arc_length,
+            noise_displacement=noise_displacement,
+            noise_scale=noise_scale,
+            noise_seed=noise_seed,
+            concentric_connected=concentric_connected,
+            clockwise=clockwise,
+            inward=inward
+        )
+        self.spirals = [[(x, y) for x, y, _ in spiral] for spiral in tapered]
+        self.widths = [[width for _, _, width in spiral] for spiral in tapered]
+
     def generate_circular_waves(
         self,
         num_circles: int = 20,
@@ -99,7 +175,10 @@ class SpiralPattern:
         end_radius: Optional[float] = None,
         points_per_circle: int = 100,
         wave_amplitude: float = 0,
-        wave_frequency: float = 5
+        wave_frequency: float = 5,
+        connect: bool = False,
+        spiral_connect: bool = False,
+        num_sides: int = 0
     ):
         """
         Generate concentric circular waves with optional undulation.
@@ -111,6 +190,11 @@ class SpiralPattern:
             points_per_circle: Points per circle
             wave_amplitude: Amplitude of waves (0 for perfect circles)
             wave_frequency: Frequency of waves
+            connect: Join circles with radial connectors into one continuous stroke
+            spiral_connect: Ease each circle into the next over its last quarter
+                turn, forming one smooth expanding curve with no radial jumps
+            num_sides: 0 for circles, or 3+ for concentric regular polygons
+                (use a points_per_circle that is a multiple to hit every corner)
         """
         self.spirals = self._generator.generate_circular_waves(
             num_circles=num_circles,
@@ -118,7 +202,10 @@ class SpiralPattern:
             end_radius=end_radius,
             points_per_circle=points_per_circle,
             wave_amplitude=wave_amplitude,
-            wave_frequency=wave_frequency
+            wave_frequency=wave_frequency,
+            connect=connect,
+            spiral_connect=spiral_connect,
+            num_sides=num_sides
         )
 
     def generate_fermat_spiral(
@@ -144,6 +231,118 @@ class SpiralPattern:
             rotation=rotation
         )
 
+    def generate_phyllotaxis(
+        self,
+        num_points: int = 1000,
+        spacing: float = 2.0,
+        angle: float = 137.5
+    ):
+        """
+        Generate phyllotaxis (Vogel spiral) points.
+
+        Produces the sunflower seed-head dot distribution. Draw the result
+        with ``as_points=True`` or use it as input to a stippler.
+
+        Args:
+            num_points: Number of points
+            spacing: Radial spacing multiplier
+            angle: Divergence angle in degrees (137.5 = golden angle)
+        """
+        points = self._generator.generate_phyllotaxis(
+            num_points=num_points,
+            spacing=spacing,
+            angle=angle
+        )
+        self.spirals = [points]
+
+    def generate_rose(
+        self,
+        k: float = 4.0,
+        amplitude: Optional[float] = None,
+        points: int = 1000
+    ):
+        """
+        Generate a rose curve (rhodonea), r = amplitude * cos(k * theta).
+
+        Rational k (e.g. 2.5 = 5/2) is swept over enough turns to close.
+
+        Args:
+            k: Petal frequency
+            amplitude: Petal length (uses max radius if None)
+            points: Number of samples along the curve
+        """
+        self.spirals = self._generator.generate_rose(
+            k=k,
+            amplitude=amplitude,
+            points=points
+        )
+
+    def generate_hypotrochoid(
+        self,
+        outer_radius: float = 80.0,
+        inner_radius: float = 30.0,
+        pen_distance: float = 50.0,
+        points: int = 5000
+    ):
+        """
+        Generate a spirograph (hypotrochoid) curve as one continuous stroke.
+
+        Args:
+            outer_radius: Radius of the fixed circle (R)
+            inner_radius: Radius of the rolling circle (r)
+            pen_distance: Pen distance from the rolling circle's center (d)
+            points: Number of samples along the curve
+        """
+        self.spirals = self._generator.generate_hypotrochoid(
+            outer_radius=outer_radius,
+            inner_radius=inner_radius,
+            pen_distance=pen_distance,
+            points=points
+        )
+
+    def generate_clothoid(
+        self,
+        length: float = 200.0,
+        curvature_rate: float = 0.001,
+        points: int = 1000
+    ):
+        """
+        Generate an Euler spiral (clothoid), whose curvature grows linearly along it.
+
+        Starts at the center heading right, nearly straight, and winds ever
+        tighter around its limit point.
+
+        Args:
+            length: Arc length of the curve
+            curvature_rate: Curvature gained per unit length (negative winds the other way)
+            points: Number of integration steps along the curve
+        """
+        self.spirals = self._generator.generate_clothoid(
+            length=length,
+            curvature_rate=curvature_rate,
+            points=points
+        )
+
+    def generate_spiral_fill(
+        self,
+        polygon: List[Tuple[float, float]],
+        spacing: float = 2.0
+    ):
+        """
+        Fill a polygon with a spiral grown from its centroid.
+
+        The spiral is clipped to the polygon and split where it exits and
+        re-enters, a common pen-plotter shading technique.
+
+        Args:
+            polygon: Polygon vertices
+            spacing: Distance between spiral rings
+        """
+        self.spirals = self._generator.generate_spiral_fill(
+            polygon=polygon,
+            spacing=spacing
+        )
+
     def draw(self, canvas: SVGCanvas, layer: str, as_points: bool = False):
         """
         Draw the spiral pattern on the canvas.
@@ -26,6 +26,7 @@ class TruchetPattern:
     - double_arc: Two quarter-circles per tile
     - triangle: Triangle patterns
     - maze: Maze-like connected lines
+    - circle: Inscribed circles or quarter-circle corner dots ("bubbles")
 
     Performance: High-performance tile generation with flexible patterns
     """
@@ -38,7 +39,14 @@ class TruchetPattern:
         grid_size: int = 20,
         randomness: float = 0.5,
         arc_segments: int = 16,
-        seed: Optional[int] = None
+        seed: Optional[int] = None,
+        noise_scale: Optional[float] = None,
+        noise_seed: int = 0,
+        grid_cols: Optional[int] = None,
+        grid_rows: Optional[int] = None,
+        rotation_weights: Optional[Tuple[float, float, float, float]] = None,
+        free_rotation: bool = False,
+        arc_width: float = 0.0
     ):
         """
         Initialize the Truchet tiles pattern generator.
@@ -46,11 +54,35 @@ class TruchetPattern:
         Args:
             width: Canvas width
             height: Canvas height
-            tile_type: Type of tile (diagonal, arc, double_arc, triangle, maze)
+            tile_type: Type of tile (diagonal, arc, double_arc, triangle, maze, circle)
             grid_size: Number of tiles along the shortest dimension
             randomness: Random vs structured (0.0 = structured, 1.0 = completely random)
             arc_segments: Number of segments for arc approximation (higher = smoother)
-            seed: Random seed for reproducibility
+            seed: Random seed for reproducibility (repeated generate calls then
+                return the same pattern)
+            noise_scale: If set, non-random tiles take their rotation from a Perlin
+                noise field with this feature size instead of a checkerboard,
+                giving large-scale flowing structure
+            noise_seed: Seed for the noise field
+            grid_cols: Number of tile columns. Setting grid_cols and/or grid_rows
+                stretches tiles to fill the whole canvas (tiles may be rectangular);
+                a missing count is chosen to keep tiles close to square. When
+                neither is set, square tiles are sized from grid_size.
+            grid_rows: Number of tile rows
+            rotation_weights: Relative weights of the 0, 90, 180 and 270 degree
+                rotations for randomized tiles (uniform if None). Use with
+                randomness=1.0 to draw every tile from these weights, e.g.
+                (1, 1, 0, 0) for a directional weave.
+            free_rotation: Additionally turn every tile about its center by an
+                arbitrary angle (noise-driven when noise_scale is set, random
+                otherwise) for swirling, gridless fields. Tiles then no longer
+                meet, so generate_joined leaves strokes unjoined.
+            arc_width: If nonzero, arc and double_arc tiles draw each arc as a
+                ribbon of two parallel curves (radius +/- arc_width/2), for a
+                bold woven look. Ribbon edges meet those of neighbors that
+                continue the arc, and ends on the border of the grid are capped.
+                Must be narrower than a tile; generate_joined leaves ribbon
+                edges unjoined.
         """
         self.width = width
         self.height = height
@@ -58,10 +90,18 @@ class TruchetPattern:
         self.grid_size = grid_size
         self.randomness = randomness
         self.arc_segments = arc_segments
-        self.seed = seed
+        self.noise_scale = noise_scale
+        self.noise_seed = noise_seed
+        self.grid_cols = grid_cols
+        self.grid_rows = grid_rows
+        self.rotation_weights = rotation_weights
+        self.free_rotation = free_rotation
+        self.arc_width = arc_width
 
         self.lines = []
         self.curves = []
+        self.connectivity = []
+        self.closed = []
 
         # Initialize Rust generator
         self._generator = _RustTruchetGenerator(
@@ -71,19 +111,82 @@ class TruchetPattern:
             grid_size=grid_size,
             randomness=randomness,
             arc_segments=arc_segments,
-            seed=seed
+            seed=seed,
+            noise_scale=noise_scale,
+            noise_seed=noise_seed,
+            grid_cols=grid_cols,
+            grid_rows=grid_rows,
+            rotation_weights=rotation_weights,
+            free_rotation=free_rotation,
+            arc_width=arc_width
         )
+        # The seed in use, drawn at random when none was given
+        self.seed = self._generator.seed
 
     def generate(self):
         """
         Generate the Truchet tiles pattern.
 
-        Creates tiles with random or structured rotations.
+        Creates tiles with random or structured rotations. With a seed, every
+        call (and every generator with the same settings) gives the same pattern.
         """
         lines, curves = self._generator.generate()
         self.lines = lines
         self.curves = curves
 
+    def generate_joined(self):
+        """
+        Generate the pattern with strokes stitched into continuous paths.
+
+        Strokes that meet end to end across tile boundaries are joined into
+        single polylines (closed loops repeat their first point), drastically
+        reducing pen lifts. All paths are stored in self.curves.
+        """
+        self.lines = []
+        self.curves = self._generator.generate_joined()
+
+    def generate_maze_path(self):
+        """
+        Trace maze corridors as long continuous polylines (tile_type="maze").
+
+        Paths are stored in self.curves; self.closed records, per path,
+        whether it is a closed loop.
+        """
+        self.lines = []
+        self.curves, self.closed = self._generator.generate_maze_path()
+
+    def generate_wang(self, num_colors: int = 2):
+        """
+        Generate an edge-matched Wang tiling of continuous arcs.
+
+        Each tile edge has one of num_colors colors that matches its neighbor;
+        same-colored edges of a tile are connected, so curves never break at
+        tile boundaries. One color gives classic two-arc Truchet; more colors
+        add straight crossings. Joined paths are stored in self.curves.
+
+        Args:
+            num_colors: Number of edge colors
+        """
+        self.lines = []
+        self.curves = self._generator.generate_wang(num_colors=num_colors)
+
+    def generate_with_connectivity(self):
+        """
+        Generate the pattern and record which tile boundary points each tile joins.
+
+        Geometry matches generate() for the same seed. self.connectivity gets one
+        (col, row, connections) entry per tile, where each connection is a pair
+        of boundary ports joined by a stroke. Ports run clockwise from the
+        top-left corner: 0 top-left, 1 top, 2 top-right, 3 right,
+        4 bottom-right, 5 bottom, 6 bottom-left, 7 left. Neighboring tiles
+        share ports on their common edge (e.g. port 3 meets the right
+        neighbor's port 7), so loops and regions can be traced across the grid.
+        """
+        lines, curves, connectivity = self._generator.generate_with_connectivity()
+        self.lines = lines
+        self.curves = curves
+        self.connectivity = connectivity
+
     def draw(self, canvas: SVGCanvas, layer: str):
         """
         Draw the Truchet pattern on the canvas.
@@ -105,6 +208,10 @@ class TruchetPattern:
         """Get all straight line segments."""
         return self.lines.copy()
 
+    def get_connectivity(self) -> List[Tuple[int, int, List[Tuple[int, int]]]]:
+        """Get per-tile port connections from generate_with_connectivity()."""
+        return [(col, row, list(conns)) for col, row, conns in self.connectivity]
+
     def get_curves(self) -> List[List[Tuple[float, float]]]:
         """Get all curve polylines."""
         return [curve.copy() for curve in self.curves]
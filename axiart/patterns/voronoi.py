@@ -1,6 +1,6 @@
 """Voronoi diagram pattern generator (Rust-accelerated)."""
 
-from typing import List, Tuple, Optional
+from typing import Callable, List, Tuple, Optional
 from ..svg_exporter import SVGCanvas
 
 try:
@@ -21,7 +21,8 @@ class VoronoiPattern:
     closest to a specific site. Perfect for organic textures, stained
     glass effects, and cellular patterns.
 
-    Performance: High-performance sampling-based edge detection
+    Performance: High-performance sampling-based edge detection, or exact
+    edges via the Delaunay dual (generate_exact)
     """
 
     def __init__(
@@ -32,7 +33,12 @@ class VoronoiPattern:
         relaxation_iterations: int = 0,
         clip_to_bounds: bool = True,
         sampling_resolution: int = 800,
-        seed: Optional[int] = None
+        seed: Optional[int] = None,
+        metric: str = "euclidean",
+        p: float = 2.0,
+        clip_polygon: Optional[List[Tuple[float, float]]] = None,
+        relaxation_samples: int = 50,
+        site_distribution: str = "random"
     ):
         """
         Initialize the Voronoi pattern generator.
@@ -45,6 +51,22 @@ class VoronoiPattern:
             clip_to_bounds: Whether to clip edges to canvas boundaries
             sampling_resolution: Resolution for edge detection (higher = more accurate but slower)
             seed: Random seed for reproducibility
+            metric: Distance metric for cell assignment and relaxation
+                (euclidean, manhattan, chebyshev, minkowski). Manhattan gives
+                rectilinear "city block" cells.
+            p: Exponent for the minkowski metric (1 = manhattan, 2 = euclidean)
+            clip_polygon: Optional region (e.g. a circle or silhouette) to confine
+                the diagram to. Sites outside it are dropped, and edges and cells
+                are clipped to it instead of the canvas rectangle.
+            relaxation_samples: Centroid sampling density for Lloyd's relaxation
+                (samples per site spacing). Higher values give tighter, more uniform
+                cells; cost per iteration grows with the square of this value
+                (200 is ~16x slower than the default 50).
+            site_distribution: How initial sites are scattered: "random"
+                (independent uniform points), "jittered_grid" (one random point
+                per grid cell) or "poisson" (blue noise with a minimum spacing,
+                giving about num_sites points). The last two start with far more
+                even cells and need fewer relaxation iterations.
         """
         self.width = width
         self.height = height
@@ -52,10 +74,16 @@ class VoronoiPattern:
         self.relaxation_iterations = relaxation_iterations
         self.clip_to_bounds = clip_to_bounds
         self.sampling_resolution = sampling_resolution
-        self.seed = seed
+        self.metric = metric
+        self.p = p
+        self.clip_polygon = clip_polygon
+        self.relaxation_samples = relaxation_samples
+        self.site_distribution = site_distribution
 
         self.sites = []
         self.edges = []
+        self.cells = []
+        self.adjacency = []
 
         # Initialize Rust generator
         self._generator = _RustVoronoiGenerator(
@@ -65,18 +93,141 @@ class VoronoiPattern:
             relaxation_iterations=relaxation_iterations,
             clip_to_bounds=clip_to_bounds,
             sampling_resolution=sampling_resolution,
-            seed=seed
+            seed=seed,
+            metric=metric,
+            p=p,
+            clip_polygon=clip_polygon,
+            relaxation_samples=relaxation_samples,
+            site_distribution=site_distribution
         )
+        # The seed in use, drawn at random when none was given
+        self.seed = self._generator.seed
 
-    def generate(self):
+    def generate(
+        self,
+        merge_edges: bool = False,
+        should_cancel: Optional[Callable[[], bool]] = None
+    ):
         """
         Generate the Voronoi diagram.
 
         Creates sites and detects cell boundaries.
+
+        Args:
+            merge_edges: Join collinear edge fragments into long segments (fewer pen lifts)
+            should_cancel: Optional callable polled before each relaxation iteration;
+                returning True stops relaxing and builds the diagram from the current sites
+        """
+        sites, edges = self._generator.generate(
+            merge_edges=merge_edges,
+            should_cancel=should_cancel
+        )
+        self.sites = sites
+        self.edges = edges
+        self.cells = []
+
+    def generate_exact(self):
+        """
+        Generate the Voronoi diagram with exact edges.
+
+        Each edge is a single clean bisector segment computed from the Delaunay
+        triangulation and clipped to the canvas, with no stair-stepping or
+        duplicates. Ignores sampling_resolution. Requires the euclidean metric.
         """
-        sites, edges = self._generator.generate()
+        sites, edges = self._generator.generate_exact()
         self.sites = sites
         self.edges = edges
+        self.cells = []
+
+    def generate_cells(self, angular_samples: int = 360):
+        """
+        Generate the Voronoi cells as closed polygons.
+
+        Each cell is an ordered polygon clipped to the canvas, ready for
+        filling, hatching or per-layer coloring. Cells are exact for the
+        euclidean metric and traced along rays for other metrics.
+
+        Args:
+            angular_samples: Rays per cell when tracing non-euclidean cells
+        """
+        self.edges = []
+        self.cells = self._generator.generate_cells(angular_samples=angular_samples)
+
+    def generate_adjacency(self):
+        """
+        Generate sites and the graph of which cells neighbor each other.
+
+        Stores index pairs (i, j) into self.sites in self.adjacency, for
+        maze, region or other graph-based compositions.
+        """
+        sites, adjacency = self._generator.generate_adjacency()
+        self.sites = sites
+        self.adjacency = adjacency
+
+    def generate_from_sites(
+        self,
+        sites: List[Tuple[float, float]],
+        relaxation_iterations: int = 0,
+        exact: bool = False,
+        merge_edges: bool = False,
+        should_cancel: Optional[Callable[[], bool]] = None
+    ):
+        """
+        Generate the Voronoi diagram for your own sites.
+
+        Useful for sites from a stippler or hand placement. The sites are
+        optionally relaxed, then edges are detected.
+
+        Args:
+            sites: Site coordinates, all within the canvas bounds
+            relaxation_iterations: Lloyd's relaxation iterations to apply (0 = keep sites as given)
+            exact: Use exact edges (as in generate_exact) instead of sampling
+            merge_edges: Join collinear sampled edge fragments into long segments
+            should_cancel: Optional callable that stops relaxation early when it returns True
+
+        Raises:
+            ValueError: If sites is empty or any site lies outside the canvas
+        """
+        sites, edges = self._generator.generate_from_sites(
+            sites=sites,
+            relaxation_iterations=relaxation_iterations,
+            exact=exact,
+            merge_edges=merge_edges,
+            should_cancel=should_cancel
+        )
+        self.sites = sites
+        self.edges = edges
+        self.cells = []
+
+    def relax_sites(self, sites, iterations: int = 1):
+        """
+        Relax your own sites with Lloyd's algorithm, without detecting edges.
+
+        A standalone centroidal Voronoi (CVT) solver for stippling and even
+        point distributions. Uses this pattern's canvas, metric, clip polygon
+        and relaxation_samples; the result is the same on any number of threads.
+
+        Args:
+            sites: (n, 2) NumPy array of x, y rows
+            iterations: Lloyd's relaxation iterations to apply
+
+        Returns:
+            (n, 2) NumPy array of relaxed sites, in the input order
+        """
+        return self._generator.relax_sites(sites, iterations)
+
+    def generate_delaunay(self):
+        """
+        Generate the Delaunay triangulation of the sites.
+
+        The triangulation is the dual of the Voronoi diagram: each edge joins
+        two sites whose cells share a boundary. Edges are stored in self.edges,
+        so draw() renders the triangulation.
+        """
+        sites, edges = self._generator.generate_delaunay()
+        self.sites = sites
+        self.edges = edges
+        self.cells = []
 
     def draw(self, canvas: SVGCanvas, layer: str, draw_sites: bool = False):
         """
@@ -91,6 +242,12 @@ class VoronoiPattern:
         for start, end in self.edges:
             canvas.add_line(start, end, layer=layer)
 
+        # Draw cells
+        for cell in self.cells:
+            if len(cell) > 1:
+                # Cells are already closed (first point repeated at the end)
+                canvas.add_polyline(cell, layer=layer)
+
         # Optionally draw sites
         if draw_sites:
             canvas.add_points(self.sites, layer=layer, radius=1.0)
@@ -99,6 +256,14 @@ class VoronoiPattern:
         """Get all Voronoi sites."""
         return self.sites.copy()
 
+    def get_cells(self) -> List[List[Tuple[float, float]]]:
+        """Get all Voronoi cells."""
+        return [cell.copy() for cell in self.cells]
+
+    def get_adjacency(self) -> List[Tuple[int, int]]:
+        """Get index pairs of neighboring cells."""
+        return self.adjacency.copy()
+
     def get_edges(self) -> List[Tuple[Tuple[float, float], Tuple[float, float]]]:
         """Get all cell boundary edges."""
         return self.edges.copy()